@@ -1,66 +1,205 @@
+use std::num::Wrapping;
+
 /// A trait to get the absolute value of a number.
+///
+/// Implemented on all integers, floats, `Wrapping` integers, and tuples of up to 4 of those.
+/// The absolute difference between two values lives in [AbsDiff](trait.AbsDiff.html).
 /// # Examples
 /// ```
 /// extern crate libaoc;
 /// use libaoc::absolute::Absolute;
+/// use std::num::Wrapping;
 /// fn main() {
 ///     assert_eq!(10, (-10i32).abs());
 ///     assert_eq!(20, 20u32.abs());
+///     assert_eq!(1.5, Absolute::abs(-1.5f64));
+///     assert_eq!((1, 2, 3), (-1, 2, -3).abs());
+///
+///     // the absolute value of i64::MIN does not fit in an i64.
+///     assert_eq!(None, Absolute::checked_abs(i64::MIN));
+///     assert_eq!(Some(5), Absolute::checked_abs(-5i64));
+///     assert_eq!(None, (1, i32::MIN).checked_abs());
+///     assert_eq!(Wrapping(i8::MIN), Absolute::abs(Wrapping(i8::MIN)));
 /// }
 /// ```
 pub trait Absolute {
+    /// Returns the absolute value of self.
+    /// Like the `abs` methods on the integers, this overflows for the minimum value of a signed integer.
     fn abs(self) -> Self;
+
+    /// Returns the absolute value of self, or `None` if it does not fit in Self.
+    /// By default this is always `Some(self.abs())`, types where `abs` can overflow override it.
+    #[inline]
+    fn checked_abs(self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        Some(self.abs())
+    }
+}
+
+/// A trait to get the absolute difference between two numbers, without overflowing.
+///
+/// Implemented on the same types as [Absolute](trait.Absolute.html).
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::absolute::AbsDiff;
+/// fn main() {
+///     // the difference between two signed integers always fits in the unsigned type.
+///     assert_eq!(u64::MAX, AbsDiff::abs_diff(i64::MIN, i64::MAX));
+///     assert_eq!(3, AbsDiff::abs_diff(2u8, 5));
+///     assert_eq!((3u8, 1u8), (2i8, -1i8).abs_diff((-1, -2)));
+///     assert_eq!(f64::INFINITY, AbsDiff::abs_diff(f64::MAX, -f64::MAX));
+/// }
+/// ```
+pub trait AbsDiff: Sized {
+    /// The type of the difference returned by [abs_diff](#tymethod.abs_diff).
+    /// For signed integers, this is the unsigned integer of the same size.
+    type Diff;
+
+    /// Returns the absolute difference between self and `other`.
+    /// For integers this never overflows. For floats it can, and then returns infinity.
+    fn abs_diff(self, other: Self) -> Self::Diff;
 }
 
 macro_rules! rec_i_absolute {
-    ($head:ty) => {
-        impl Absolute for $head {
-            #[inline(always)]
-            fn abs(self) -> Self {
-                self.abs()
+    ($($signed:ty => $unsigned:ty),+) => {
+        $(
+            impl Absolute for $signed {
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+
+                #[inline(always)]
+                fn checked_abs(self) -> Option<Self> {
+                    self.checked_abs()
+                }
             }
-        }
-    };
-    ($head:ty, $($tail:ty),*) => {
-        rec_i_absolute!($head);
-        rec_i_absolute!($($tail),*);
+
+            impl AbsDiff for $signed {
+                type Diff = $unsigned;
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> $unsigned {
+                    self.abs_diff(other)
+                }
+            }
+
+            impl Absolute for Wrapping<$signed> {
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    Wrapping(self.0.wrapping_abs())
+                }
+            }
+
+            impl AbsDiff for Wrapping<$signed> {
+                type Diff = Wrapping<$unsigned>;
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> Wrapping<$unsigned> {
+                    Wrapping(self.0.abs_diff(other.0))
+                }
+            }
+        )+
     };
 }
 
 macro_rules! rec_u_absolute {
-    ($head:ty) => {
-        impl Absolute for $head {
-            #[inline(always)]
-            fn abs(self) -> Self {
-                self
+    ($($unsigned:ty),+) => {
+        $(
+            impl Absolute for $unsigned {
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    self
+                }
             }
-        }
+
+            impl AbsDiff for $unsigned {
+                type Diff = $unsigned;
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> $unsigned {
+                    self.abs_diff(other)
+                }
+            }
+
+            impl Absolute for Wrapping<$unsigned> {
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    self
+                }
+            }
+
+            impl AbsDiff for Wrapping<$unsigned> {
+                type Diff = Wrapping<$unsigned>;
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> Wrapping<$unsigned> {
+                    Wrapping(self.0.abs_diff(other.0))
+                }
+            }
+        )+
     };
-    ($head:ty, $($tail:ty),*) => {
-        rec_u_absolute!($head);
-        rec_u_absolute!($($tail),*);
+}
+
+macro_rules! rec_f_absolute {
+    ($($float:ty),+) => {
+        $(
+            impl Absolute for $float {
+                #[inline(always)]
+                fn abs(self) -> Self {
+                    self.abs()
+                }
+            }
+
+            impl AbsDiff for $float {
+                type Diff = $float;
+
+                #[inline(always)]
+                fn abs_diff(self, other: Self) -> $float {
+                    (self - other).abs()
+                }
+            }
+        )+
     };
 }
 
-//@FIXME!
-// macro_rules! rec_u_absolute {
-//     ($($tail:ty,)+) => {
-//         impl Absolute for $tail {
-//             #[inline(always)]
-//             fn abs(self) -> Self {
-//                 self
-//             }
-//         }
-//         rec_u_absolute!($($tail,)*);
-//     };
-// }
-
-rec_i_absolute!(i64, i32, i16, i8, isize);
-rec_u_absolute!(u64, u32, u16, u8, usize);
-
-impl<N: Absolute> Absolute for (N, N) {
-    #[inline]
-    fn abs(self) -> Self {
-        (self.0.abs(), self.1.abs())
-    }
+rec_i_absolute!(i128 => u128, i64 => u64, i32 => u32, i16 => u16, i8 => u8, isize => usize);
+rec_u_absolute!(u128, u64, u32, u16, u8, usize);
+rec_f_absolute!(f64, f32);
+
+// `$n` is always `N`, it is only there to repeat the type once for every field.
+macro_rules! tuple_absolute {
+    ($(($($idx:tt => $n:ident),+)),+) => {
+        $(
+            impl<N: Absolute> Absolute for ($($n,)+) {
+                #[inline]
+                fn abs(self) -> Self {
+                    ($(self.$idx.abs(),)+)
+                }
+
+                #[inline]
+                fn checked_abs(self) -> Option<Self> {
+                    Some(($(self.$idx.checked_abs()?,)+))
+                }
+            }
+
+            impl<N: AbsDiff> AbsDiff for ($($n,)+) {
+                type Diff = ($(<$n as AbsDiff>::Diff,)+);
+
+                #[inline]
+                fn abs_diff(self, other: Self) -> Self::Diff {
+                    ($(self.$idx.abs_diff(other.$idx),)+)
+                }
+            }
+        )+
+    };
 }
+
+tuple_absolute!(
+    (0 => N, 1 => N),
+    (0 => N, 1 => N, 2 => N),
+    (0 => N, 1 => N, 2 => N, 3 => N)
+);
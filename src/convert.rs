@@ -1,4 +1,7 @@
-use std::iter::Map;
+use std::error::Error;
+use std::iter::{Map, Peekable};
+use std::mem::MaybeUninit;
+use std::ptr;
 use std::str::FromStr;
 
 /// This trait allows to convert a stream of `str`'s, into a stream or collection of type U.
@@ -72,6 +75,113 @@ where
     /// ```
     fn try_convert_into_slice(self, slice: &mut [U]) -> Result<usize, usize>;
 
+    /// Converts the stream into `slice`, which must be filled exactly.
+    /// Fails if an item can not be converted, or if the stream has less or more items than the slice is long.
+    /// On an error, the items before it are already written to the slice.
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate libaoc;
+    ///
+    /// use libaoc::convert::{ConvertError, TryConvert};
+    ///
+    /// fn main() {
+    ///     let mut buff = [0i64; 3];
+    ///
+    ///     assert_eq!(Ok(()), "1 2 3".split(' ').try_convert_into_slice_exact(&mut buff));
+    ///     assert_eq!([1, 2, 3], buff);
+    ///
+    ///     let error = "4 x 6".split(' ').try_convert_into_slice_exact(&mut buff).unwrap_err();
+    ///     match error {
+    ///         ConvertError::Parse { index, error } => {
+    ///             assert_eq!(1, index);
+    ///             assert_eq!("invalid digit found in string", error.to_string());
+    ///         }
+    ///         _ => panic!(),
+    ///     }
+    ///
+    ///     assert_eq!(
+    ///         Err(ConvertError::TooFew { expected: 3, found: 2 }),
+    ///         "1 2".split(' ').try_convert_into_slice_exact(&mut buff)
+    ///     );
+    ///     assert_eq!(
+    ///         Err(ConvertError::TooMany { expected: 3 }),
+    ///         "1 2 3 4".split(' ').try_convert_into_slice_exact(&mut buff)
+    ///     );
+    /// }
+    /// ```
+    fn try_convert_into_slice_exact(self, slice: &mut [U])
+        -> Result<(), ConvertError<Self::Error>>;
+
+    /// Converts the stream into an array, which must be filled exactly.
+    /// Fails the same way as [try_convert_into_slice_exact](#tymethod.try_convert_into_slice_exact).
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate libaoc;
+    ///
+    /// use libaoc::convert::{ConvertError, TryConvert};
+    ///
+    /// fn main() {
+    ///     let [x, y, z] = "1,-2,3".split(',').try_convert_array::<3>().unwrap();
+    ///     assert_eq!((1i32, -2, 3), (x, y, z));
+    ///
+    ///     let result: Result<[u8; 2], _> = "7".split(',').try_convert_array();
+    ///     assert_eq!(Err(ConvertError::TooFew { expected: 2, found: 1 }), result);
+    /// }
+    /// ```
+    fn try_convert_array<const N: usize>(self) -> Result<[U; N], ConvertError<Self::Error>>;
+
+    /// Same as [try_convert](#tymethod.try_convert), but every item is first prepared according to `options`.
+    /// On an error, returns the error together with the index of the item in the stream, counted from 0. Skipped items are counted too.
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate libaoc;
+    ///
+    /// use libaoc::convert::{ConvertError, ConvertOptions, TryConvert};
+    ///
+    /// fn main() {
+    ///     let s = "1, 2,3\n";
+    ///     let strict: Result<Vec<i32>, _> = s.split(',').try_convert();
+    ///     assert!(strict.is_err());
+    ///
+    ///     let options = ConvertOptions::new().trim(true);
+    ///     assert_eq!(vec![1, 2, 3], s.split(',').try_convert_with(options).unwrap());
+    ///
+    ///     let s = "1\n2\n\n3\n";
+    ///     let options = ConvertOptions::tolerant();
+    ///     assert_eq!(vec![1, 2, 3], s.split('\n').try_convert_with(options).unwrap());
+    ///
+    ///     let result: Result<Vec<i32>, _> = "1\n\nx\n".split('\n').try_convert_with(options);
+    ///     match result {
+    ///         Err(ConvertError::Parse { index, .. }) => assert_eq!(2, index),
+    ///         _ => panic!(),
+    ///     }
+    /// }
+    /// ```
+    fn try_convert_with(self, options: ConvertOptions)
+        -> Result<Vec<U>, ConvertError<Self::Error>>;
+
+    /// Converts every item, prepared according to `options`, and collects both the converted items and all errors.
+    /// Every error comes with the index of its item in the stream, counted from 0. Skipped items are counted too.
+    /// # Examples
+    ///
+    /// ```
+    /// extern crate libaoc;
+    ///
+    /// use libaoc::convert::{ConvertOptions, TryConvert};
+    ///
+    /// fn main() {
+    ///     let s = "1, x, 3,, -y";
+    ///     let (numbers, errors) = s.split(',').try_convert_all(ConvertOptions::tolerant());
+    ///
+    ///     assert_eq!(vec![1i32, 3], numbers);
+    ///     assert_eq!(vec![1, 4], errors.iter().map(|&(index, _)| index).collect::<Vec<_>>());
+    /// }
+    /// ```
+    fn try_convert_all(self, options: ConvertOptions) -> (Vec<U>, Vec<(usize, Self::Error)>);
+
     /// Returns an iterator over the converted items. Returns an error if an item can not be converted. Continue's after the error.
     fn try_convert_iter(self) -> Self::Iterable;
 }
@@ -106,12 +216,173 @@ where
         Ok(number_of_writes)
     }
 
+    fn try_convert_into_slice_exact(
+        self,
+        slice: &mut [U],
+    ) -> Result<(), ConvertError<Self::Error>> {
+        let mut written = 0;
+        for (index, item) in self.try_convert_iter().enumerate() {
+            if written == slice.len() {
+                return Err(ConvertError::TooMany {
+                    expected: slice.len(),
+                });
+            }
+            slice[written] = item.map_err(|error| ConvertError::Parse { index, error })?;
+            written += 1;
+        }
+
+        if written == slice.len() {
+            Ok(())
+        } else {
+            Err(ConvertError::TooFew {
+                expected: slice.len(),
+                found: written,
+            })
+        }
+    }
+
+    fn try_convert_array<const N: usize>(self) -> Result<[U; N], ConvertError<Self::Error>> {
+        let mut array = PartialArray::new();
+        for (index, item) in self.try_convert_iter().enumerate() {
+            if array.filled == N {
+                return Err(ConvertError::TooMany { expected: N });
+            }
+            array.push(item.map_err(|error| ConvertError::Parse { index, error })?);
+        }
+
+        if array.filled == N {
+            Ok(array.finish())
+        } else {
+            Err(ConvertError::TooFew {
+                expected: N,
+                found: array.filled,
+            })
+        }
+    }
+
+    fn try_convert_with(
+        self,
+        options: ConvertOptions,
+    ) -> Result<Vec<U>, ConvertError<Self::Error>> {
+        let mut converted = Vec::new();
+        for (index, item) in self.enumerate() {
+            if let Some(text) = options.prepare(item.as_ref()) {
+                converted.push(
+                    text.parse()
+                        .map_err(|error| ConvertError::Parse { index, error })?,
+                );
+            }
+        }
+        Ok(converted)
+    }
+
+    fn try_convert_all(self, options: ConvertOptions) -> (Vec<U>, Vec<(usize, Self::Error)>) {
+        let mut converted = Vec::new();
+        let mut errors = Vec::new();
+        for (index, item) in self.enumerate() {
+            if let Some(text) = options.prepare(item.as_ref()) {
+                match text.parse() {
+                    Ok(value) => converted.push(value),
+                    Err(error) => errors.push((index, error)),
+                }
+            }
+        }
+        (converted, errors)
+    }
+
     #[inline]
     fn try_convert_iter(self) -> Self::Iterable {
         self.map(|item| item.as_ref().parse())
     }
 }
 
+/// How the items are prepared before [TryConvert](trait.TryConvert.html) parses them.
+/// The default does nothing, so an item is parsed exactly as it is.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConvertOptions {
+    trim: bool,
+    skip_empty: bool,
+}
+
+impl ConvertOptions {
+    /// Returns options that leave the items as they are.
+    #[inline]
+    pub fn new() -> ConvertOptions {
+        ConvertOptions::default()
+    }
+
+    /// Returns options that trim the items, and skip the items that are empty after trimming.
+    #[inline]
+    pub fn tolerant() -> ConvertOptions {
+        ConvertOptions::new().trim(true).skip_empty(true)
+    }
+
+    /// Sets whether whitespace is trimmed from both ends of an item.
+    #[inline]
+    pub fn trim(mut self, trim: bool) -> ConvertOptions {
+        self.trim = trim;
+        self
+    }
+
+    /// Sets whether empty items are skipped. This is checked after trimming.
+    #[inline]
+    pub fn skip_empty(mut self, skip_empty: bool) -> ConvertOptions {
+        self.skip_empty = skip_empty;
+        self
+    }
+
+    /// Returns the text to parse, or `None` if the item is skipped.
+    #[inline]
+    fn prepare(self, item: &str) -> Option<&str> {
+        let item = if self.trim { item.trim() } else { item };
+        if self.skip_empty && item.is_empty() {
+            None
+        } else {
+            Some(item)
+        }
+    }
+}
+
+/// The error returned by the strict conversions of [TryConvert](trait.TryConvert.html), and by [try_convert_with](trait.TryConvert.html#tymethod.try_convert_with).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ConvertError<E> {
+    /// The item at `index` could not be converted. `index` counts from 0.
+    Parse { index: usize, error: E },
+    /// The stream only had `found` items.
+    TooFew { expected: usize, found: usize },
+    /// The stream had more than `expected` items.
+    TooMany { expected: usize },
+}
+
+impl<E: ::std::fmt::Display> ::std::fmt::Display for ConvertError<E> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ConvertError::Parse { index, ref error } => {
+                write!(f, "could not convert item {}: {}", index, error)
+            }
+            ConvertError::TooFew { expected, found } => {
+                write!(
+                    f,
+                    "expected {} items, but there were only {}",
+                    expected, found
+                )
+            }
+            ConvertError::TooMany { expected } => {
+                write!(f, "expected {} items, but there were more", expected)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ConvertError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ConvertError::Parse { ref error, .. } => Some(error),
+            ConvertError::TooFew { .. } | ConvertError::TooMany { .. } => None,
+        }
+    }
+}
+
 /// This trait allows to convert a stream with items of type T into a stream or collection with items of type U.
 ///
 /// # Examples
@@ -201,33 +472,375 @@ where
     }
 }
 
-#[derive(Debug, Eq, PartialEq, Ord, PartialOrd)]
-pub enum FillError {
-    FillError,
+/// This trait allows to convert a single `char` into a type, the way `FromStr` does for a `str`.
+/// Implement this for the cells of a character map, and parse the whole map with [Grid::parse](../grid/struct.Grid.html#method.parse).
+///
+/// It is implemented for `char` itself, for `bool` (`'#'` is `true`, `'.'` is `false`),
+/// and for all integers, where the char is read as a decimal digit.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+///
+/// use libaoc::convert::{FromChar, FromCharError};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Tile {
+///     Open,
+///     Wall,
+/// }
+///
+/// impl FromChar for Tile {
+///     type Err = FromCharError;
+///
+///     fn from_char(c: char) -> Result<Tile, FromCharError> {
+///         match c {
+///             '.' => Ok(Tile::Open),
+///             '#' => Ok(Tile::Wall),
+///             _ => Err(FromCharError(c)),
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(Ok(Tile::Wall), Tile::from_char('#'));
+///     assert_eq!(Err(FromCharError('x')), Tile::from_char('x'));
+///
+///     assert_eq!(Ok(7u8), u8::from_char('7'));
+///     assert_eq!(Ok(true), bool::from_char('#'));
+/// }
+/// ```
+pub trait FromChar: Sized {
+    /// The Errortype that gets returned on an error.
+    type Err;
+
+    /// Converts `c` into Self.
+    fn from_char(c: char) -> Result<Self, Self::Err>;
 }
 
-impl ::std::error::Error for FillError {
+/// The error returned when a `char` can not be converted by [FromChar](trait.FromChar.html). Holds the offending char.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct FromCharError(pub char);
+
+impl ::std::error::Error for FromCharError {}
+
+impl ::std::fmt::Display for FromCharError {
     #[inline]
-    fn description(&self) -> &str {
-        match *self {
-            FillError::FillError => "The array was partially filled, and therefore dropped.",
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "invalid character {:?}", self.0)
+    }
+}
+
+impl FromChar for char {
+    type Err = ::std::convert::Infallible;
+
+    #[inline]
+    fn from_char(c: char) -> Result<char, Self::Err> {
+        Ok(c)
+    }
+}
+
+impl FromChar for bool {
+    type Err = FromCharError;
+
+    #[inline]
+    fn from_char(c: char) -> Result<bool, Self::Err> {
+        match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(FromCharError(c)),
         }
     }
 }
 
+macro_rules! digit_from_char {
+    ($($int:ty),*) => {
+        $(
+            impl FromChar for $int {
+                type Err = FromCharError;
+
+                #[inline]
+                fn from_char(c: char) -> Result<$int, Self::Err> {
+                    match c.to_digit(10) {
+                        Some(digit) => Ok(digit as $int),
+                        None => Err(FromCharError(c)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+digit_from_char!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// The error returned when an array could not be filled, because the iterator ran out of items.
+/// The items that were already collected have been dropped.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Copy, Hash)]
+pub struct FillError {
+    /// How many items were filled, before the iterator ran out.
+    pub filled: usize,
+    /// The length of the array.
+    pub expected: usize,
+}
+
+impl ::std::error::Error for FillError {}
+
 impl ::std::fmt::Display for FillError {
     #[inline]
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        write!(f, "{}", self)
+        write!(
+            f,
+            "The array was partially filled with {} of {} items, and therefore dropped.",
+            self.filled, self.expected
+        )
+    }
+}
+
+/// The error returned by [collect_array_exact](trait.CollectArray.html#tymethod.collect_array_exact),
+/// when the iterator did not have exactly the right number of items.
+///
+/// No item is lost: the items that were already collected are moved into a Vec, so unlike a [FillError](struct.FillError.html), creating this error allocates.
+/// `I` is the type of the leftover iterator.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Hash)]
+pub enum ExactFillError<T, I> {
+    /// The iterator ran out before the array was full.
+    TooFew { error: FillError, items: Vec<T> },
+    /// The array was full, but the iterator had more items. `rest` still yields all of those.
+    TooMany {
+        expected: usize,
+        items: Vec<T>,
+        rest: I,
+    },
+}
+
+impl<T, I> ExactFillError<T, I> {
+    /// Returns the length of the array that was being filled.
+    #[inline]
+    pub fn expected(&self) -> usize {
+        match *self {
+            ExactFillError::TooFew { error, .. } => error.expected,
+            ExactFillError::TooMany { expected, .. } => expected,
+        }
+    }
+
+    /// Returns how many items were collected, before the error occured.
+    #[inline]
+    pub fn filled(&self) -> usize {
+        match *self {
+            ExactFillError::TooFew { ref items, .. }
+            | ExactFillError::TooMany { ref items, .. } => items.len(),
+        }
+    }
+
+    /// Returns the items that were collected.
+    #[inline]
+    pub fn into_items(self) -> Vec<T> {
+        match self {
+            ExactFillError::TooFew { items, .. } | ExactFillError::TooMany { items, .. } => items,
+        }
+    }
+}
+
+impl<T: ::std::fmt::Debug, I: ::std::fmt::Debug> ::std::error::Error for ExactFillError<T, I> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ExactFillError::TooFew { ref error, .. } => Some(error),
+            ExactFillError::TooMany { .. } => None,
+        }
+    }
+}
+
+impl<T, I> ::std::fmt::Display for ExactFillError<T, I> {
+    #[inline]
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        match *self {
+            ExactFillError::TooFew { error, .. } => write!(
+                f,
+                "The array was partially filled with {} of {} items.",
+                error.filled, error.expected
+            ),
+            ExactFillError::TooMany { expected, .. } => write!(
+                f,
+                "The array was filled with {} items, but the iterator had more.",
+                expected
+            ),
+        }
+    }
+}
+
+/// An array that is filled from the front. Only the first `filled` items are initialized,
+/// and only those get dropped when the array is dropped before it is full.
+struct PartialArray<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    filled: usize,
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    #[inline]
+    fn new() -> PartialArray<T, N> {
+        PartialArray {
+            data: [const { MaybeUninit::uninit() }; N],
+            filled: 0,
+        }
+    }
+
+    /// Writes `item` to the next slot. Must not be called on a full array.
+    #[inline]
+    fn push(&mut self, item: T) {
+        self.data[self.filled] = MaybeUninit::new(item);
+        self.filled += 1;
+    }
+
+    /// Returns the array. Must only be called on a full array.
+    #[inline]
+    fn finish(mut self) -> [T; N] {
+        debug_assert_eq!(self.filled, N);
+        // every item is initialized, and ownership moves out of `self.data`,
+        // so `filled` is reset to keep Drop from dropping them again.
+        let array = unsafe { ptr::read(self.data.as_ptr() as *const [T; N]) };
+        self.filled = 0;
+        array
+    }
+}
+
+impl<T, const N: usize> PartialArray<T, N> {
+    /// Moves the items that were filled into a Vec.
+    #[inline]
+    fn into_vec(mut self) -> Vec<T> {
+        let filled = self.filled;
+        // the items are moved out, so Drop must not drop them anymore.
+        self.filled = 0;
+        self.data[..filled]
+            .iter()
+            .map(|item| unsafe { item.as_ptr().read() })
+            .collect()
+    }
+}
+
+impl<T, const N: usize> Drop for PartialArray<T, N> {
+    #[inline]
+    fn drop(&mut self) {
+        for item in &mut self.data[..self.filled] {
+            unsafe { ptr::drop_in_place(item.as_mut_ptr()) }
+        }
+    }
+}
+
+/// Collects an Iterator into an array, without allocating.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::convert::{CollectArray, ExactFillError, FillError};
+/// fn main() {
+///     let mut iter = "a b c d".split(' ');
+///
+///     assert_eq!(Ok(["a", "b"]), (&mut iter).collect_array());
+///     assert_eq!(Some("c"), iter.next());
+///
+///     let err = (1..3).collect_array::<i32, 5>().unwrap_err();
+///     assert_eq!(FillError { filled: 2, expected: 5 }, err);
+///
+///     match (1..3).collect_array_exact::<i32, 5>() {
+///         Err(ExactFillError::TooFew { error, items }) => {
+///             assert_eq!(FillError { filled: 2, expected: 5 }, error);
+///             assert_eq!(vec![1, 2], items);
+///         }
+///         _ => panic!(),
+///     }
+///
+///     match (1..5).collect_array_exact::<i32, 2>() {
+///         Err(ExactFillError::TooMany { items, rest, .. }) => {
+///             assert_eq!(vec![1, 2], items);
+///             assert_eq!(vec![3, 4], rest.collect::<Vec<_>>());
+///         }
+///         _ => panic!(),
+///     }
+///     assert_eq!([1, 2], (1..3).collect_array_exact().unwrap());
+/// }
+/// ```
+pub trait CollectArray: Iterator + Sized {
+    /// Collects the next N items into an array.
+    /// Exactly N items are taken from the iterator, so by calling this on `&mut iter`, the rest of the items can still be used.
+    ///
+    /// If the iterator runs out before the array is full, the items collected so far are dropped, and a [FillError](struct.FillError.html) is returned.
+    /// The same happens if the iterator panics.
+    fn collect_array<T, const N: usize>(self) -> Result<[T; N], FillError>
+    where
+        Self: Iterator<Item = T>;
+
+    /// Same as [collect_array](#tymethod.collect_array), but the iterator must have exactly N items.
+    /// On failure, the items collected so far are returned in a Vec, together with the rest of the iterator if it had too many items.
+    fn collect_array_exact<T, const N: usize>(
+        self,
+    ) -> Result<[T; N], ExactFillError<T, Peekable<Self>>>
+    where
+        Self: Iterator<Item = T>;
+}
+
+/// Fills a PartialArray from `iter`. If it runs out, the partially filled array is returned as the error.
+#[inline]
+fn fill<T, I, const N: usize>(iter: &mut I) -> Result<PartialArray<T, N>, PartialArray<T, N>>
+where
+    I: Iterator<Item = T>,
+{
+    let mut array = PartialArray::new();
+    while array.filled < N {
+        match iter.next() {
+            Some(item) => array.push(item),
+            None => return Err(array),
+        }
+    }
+    Ok(array)
+}
+
+impl<I: Iterator> CollectArray for I {
+    fn collect_array<T, const N: usize>(mut self) -> Result<[T; N], FillError>
+    where
+        Self: Iterator<Item = T>,
+    {
+        match fill(&mut self) {
+            Ok(array) => Ok(array.finish()),
+            Err(array) => Err(FillError {
+                filled: array.filled,
+                expected: N,
+            }),
+        }
+    }
+
+    fn collect_array_exact<T, const N: usize>(
+        self,
+    ) -> Result<[T; N], ExactFillError<T, Peekable<Self>>>
+    where
+        Self: Iterator<Item = T>,
+    {
+        let mut iter = self.peekable();
+        match fill(&mut iter) {
+            Ok(array) => {
+                if iter.peek().is_some() {
+                    Err(ExactFillError::TooMany {
+                        expected: N,
+                        items: array.into_vec(),
+                        rest: iter,
+                    })
+                } else {
+                    Ok(array.finish())
+                }
+            }
+            Err(array) => Err(ExactFillError::TooFew {
+                error: FillError {
+                    filled: array.filled,
+                    expected: N,
+                },
+                items: array.into_vec(),
+            }),
+        }
     }
 }
 
 /// This macro makes it easy to convert an Iterator into an array.
 /// The `type` of the array has to be specified when this macro is called.
 ///
-/// The array that get's build uses mem::unitialized to prevent unnecessary allocation,
-/// however if the Iterator has less items than the lenght of the array, this means there is still
-/// unitialized memory. In this case, the macro will return an error, and drop the array that was build.
+/// This is a shorthand for [CollectArray::collect_array](convert/trait.CollectArray.html#tymethod.collect_array).
+/// If the Iterator has less items than the lenght of the array, the macro will return an error, and drop the items that were collected.
 /// # Examples
 /// ```
 /// #[macro_use]
@@ -253,68 +866,7 @@ impl ::std::fmt::Display for FillError {
 /// ```
 #[macro_export]
 macro_rules! arraycollect {
-    ($iter:expr => [$tgt:ty; $num:tt]) => (
-        {
-            use ::std::mem;
-
-            struct PartialArray<T> {
-                data: mem::ManuallyDrop<[T; $num]>,
-                fill: usize,
-            }
-
-            impl <T>PartialArray<T> {
-                #[inline]
-                fn new() -> PartialArray<T> {
-                    unsafe {
-                        PartialArray {
-                            data: mem::ManuallyDrop::new(mem::uninitialized()),
-                            fill: 0,
-                        }
-                    }
-                }
-
-                #[inline]
-                fn fill_array<I: Iterator<Item = T>>(mut self, iter: I) -> Result<[T; $num], $crate::convert::FillError>
-                {
-                    for (dst, src) in self.data.iter_mut().zip(iter) {
-                        unsafe {
-                            ::std::ptr::write(dst, src);
-                        }
-                        self.fill += 1;
-                    }
-
-                    //if the number of items filled is not equal to the number of items that should have been written,
-                    //return an error.
-                    if self.fill != $num {
-                        Err($crate::convert::FillError::FillError)
-                    }
-                    else {
-                        Ok(self.finish())
-                    }
-                }
-                #[inline]
-                fn finish(mut self) -> [T; $num] {
-                    unsafe {
-                        let rd = ::std::ptr::read(&mut self.data);
-                        let ret = mem::ManuallyDrop::into_inner(rd);
-                        mem::forget(self);
-                        ret
-                    }
-                }
-            }
-
-            impl <T>Drop for PartialArray<T> {
-                #[inline]
-                fn drop(&mut self) {
-                    unsafe {
-                        ::std::ptr::drop_in_place::<[T]>(&mut self.data[0..self.fill]);
-                    }
-                }
-            }
-
-            //pass in $tgt as generic paremeter, fill_array takes an array with items of $tgt.
-            let array: PartialArray<$tgt> = PartialArray::new();
-            array.fill_array($iter)
-        }
-    )
+    ($iter:expr => [$tgt:ty; $num:tt]) => {
+        $crate::convert::CollectArray::collect_array::<$tgt, $num>($iter)
+    };
 }
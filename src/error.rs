@@ -0,0 +1,248 @@
+//! A single error type for the whole crate, so `?` can be used on anything this crate returns.
+//!
+//! Every error of this crate converts into an [Error](enum.Error.html), and so do the errors of parsing the std types.
+//! Parse errors of other types can be wrapped with [Error::parse](enum.Error.html#method.parse).
+use convert::{ConvertError, ExactFillError, FillError, FromCharError};
+use graph::{CycleError, ParseGraphError};
+use grid::ParseGridError;
+use movement::{ParseDirectionError, ParseInstructionError};
+use parse::{GroupError, ParseStructError, ScanError};
+use reading::{LineError, MissingInput, ReadError};
+use runner::RunError;
+use std::char::ParseCharError;
+use std::error::Error as StdError;
+use std::fmt;
+use std::io;
+use std::num::{ParseFloatError, ParseIntError};
+use std::result;
+use std::str::{ParseBoolError, Utf8Error};
+use std::string::FromUtf8Error;
+
+/// A `Result` with [Error](enum.Error.html) as its error.
+pub type Result<T> = result::Result<T, Error>;
+
+/// The error type of this crate.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::convert::TryConvert;
+/// use libaoc::Error;
+///
+/// fn sum(input: &str) -> libaoc::Result<i64> {
+///     let numbers: Vec<i64> = input.split(',').try_convert()?;
+///     Ok(numbers.iter().sum())
+/// }
+///
+/// fn main() {
+///     assert_eq!(6, sum("1,2,3").unwrap());
+///
+///     match sum("1,two,3") {
+///         Err(Error::Parse(e)) => assert_eq!("invalid digit found in string", e.to_string()),
+///         _ => panic!(),
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// Reading or writing failed.
+    Io(io::Error),
+    /// Some input was not valid UTF-8.
+    Utf8(Utf8Error),
+    /// Some input could not be parsed.
+    Parse(Box<dyn StdError + Send + Sync>),
+    /// An array could not be filled from an iterator.
+    Fill(FillError),
+    /// An iterator had more than the `expected` number of items.
+    TooMany { expected: usize },
+    /// Any other error of this crate, like a [CycleError](../graph/struct.CycleError.html).
+    Other(Box<dyn StdError + Send + Sync>),
+}
+
+impl Error {
+    /// Wraps the error of parsing any type into an `Error::Parse`.
+    #[inline]
+    pub fn parse<E: StdError + Send + Sync + 'static>(error: E) -> Error {
+        Error::Parse(Box::new(error))
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Io(ref error) => write!(f, "{}", error),
+            Error::Utf8(ref error) => write!(f, "{}", error),
+            Error::Parse(ref error) => write!(f, "{}", error),
+            Error::Fill(ref error) => write!(f, "{}", error),
+            Error::TooMany { expected } => {
+                write!(f, "expected {} items, but there were more", expected)
+            }
+            Error::Other(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match *self {
+            Error::Io(ref error) => Some(error),
+            Error::Utf8(ref error) => Some(error),
+            Error::Parse(ref error) => Some(&**error),
+            Error::Fill(ref error) => Some(error),
+            Error::TooMany { .. } => None,
+            Error::Other(ref error) => Some(&**error),
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    #[inline]
+    fn from(error: io::Error) -> Error {
+        Error::Io(error)
+    }
+}
+
+impl From<Utf8Error> for Error {
+    #[inline]
+    fn from(error: Utf8Error) -> Error {
+        Error::Utf8(error)
+    }
+}
+
+impl From<FromUtf8Error> for Error {
+    #[inline]
+    fn from(error: FromUtf8Error) -> Error {
+        Error::Utf8(error.utf8_error())
+    }
+}
+
+impl From<FillError> for Error {
+    #[inline]
+    fn from(error: FillError) -> Error {
+        Error::Fill(error)
+    }
+}
+
+/// The collected items and the leftover iterator are dropped.
+impl<T, I> From<ExactFillError<T, I>> for Error {
+    #[inline]
+    fn from(error: ExactFillError<T, I>) -> Error {
+        match error {
+            ExactFillError::TooFew { error, .. } => Error::Fill(error),
+            ExactFillError::TooMany { expected, .. } => Error::TooMany { expected },
+        }
+    }
+}
+
+impl<E: StdError + Send + Sync + 'static> From<ConvertError<E>> for Error {
+    #[inline]
+    fn from(error: ConvertError<E>) -> Error {
+        match error {
+            ConvertError::Parse { .. } => Error::parse(error),
+            ConvertError::TooFew { expected, found } => Error::Fill(FillError {
+                filled: found,
+                expected,
+            }),
+            ConvertError::TooMany { expected } => Error::TooMany { expected },
+        }
+    }
+}
+
+/// Turns an `Error` back into an `io::Error`, for APIs that can only report those.
+/// Anything but `Error::Io` becomes an error of kind `InvalidData`.
+impl From<Error> for io::Error {
+    #[inline]
+    fn from(error: Error) -> io::Error {
+        match error {
+            Error::Io(error) => error,
+            error => io::Error::new(io::ErrorKind::InvalidData, error),
+        }
+    }
+}
+
+macro_rules! parse_error {
+    ($($error:ty),+) => {
+        $(
+            impl From<$error> for Error {
+                #[inline]
+                fn from(error: $error) -> Error {
+                    Error::parse(error)
+                }
+            }
+        )+
+    };
+}
+
+parse_error!(
+    ParseIntError,
+    ParseFloatError,
+    ParseBoolError,
+    ParseCharError,
+    FromCharError,
+    ParseDirectionError,
+    ScanError,
+    ParseStructError
+);
+
+macro_rules! generic_parse_error {
+    ($($error:ident),+) => {
+        $(
+            impl<E: StdError + Send + Sync + 'static> From<$error<E>> for Error {
+                #[inline]
+                fn from(error: $error<E>) -> Error {
+                    Error::parse(error)
+                }
+            }
+        )+
+    };
+}
+
+generic_parse_error!(
+    ParseGridError,
+    ParseGraphError,
+    ParseInstructionError,
+    GroupError
+);
+
+impl<E: StdError + Send + Sync + 'static> From<ReadError<E>> for Error {
+    #[inline]
+    fn from(error: ReadError<E>) -> Error {
+        match error {
+            ReadError::Io(error) => Error::Io(error),
+            error => Error::parse(error),
+        }
+    }
+}
+
+impl<E: StdError + Send + Sync + 'static> From<LineError<E>> for Error {
+    #[inline]
+    fn from(error: LineError<E>) -> Error {
+        match error {
+            LineError::Io { error, .. } => Error::Io(error),
+            error => Error::parse(error),
+        }
+    }
+}
+
+impl From<MissingInput> for Error {
+    #[inline]
+    fn from(error: MissingInput) -> Error {
+        Error::Io(io::Error::new(io::ErrorKind::NotFound, error))
+    }
+}
+
+impl From<CycleError> for Error {
+    #[inline]
+    fn from(error: CycleError) -> Error {
+        Error::Other(Box::new(error))
+    }
+}
+
+impl From<RunError> for Error {
+    #[inline]
+    fn from(error: RunError) -> Error {
+        match error {
+            RunError::Io(error) => Error::Io(error),
+            error => Error::Other(Box::new(error)),
+        }
+    }
+}
@@ -0,0 +1,555 @@
+use convert::TryConvert;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::hash::Hash;
+use std::slice;
+use std::str::FromStr;
+
+/// A directed graph, stored as adjacency lists. Every edge carries a weight of type `E`.
+/// Nodes are stored in the order they were first added, and every algorithm visits them in that order.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::graph::Graph;
+/// fn main() {
+///     let mut graph = Graph::new();
+///     graph.add_edge("a", "b", 3);
+///     graph.add_undirected_edge("b", "c", 5);
+///
+///     assert_eq!(3, graph.node_count());
+///     assert_eq!(3, graph.edge_count());
+///
+///     let from_b: Vec<_> = graph.neighbours(&"b").collect();
+///     assert_eq!(vec![(&"c", &5)], from_b);
+///     assert_eq!(0, graph.neighbours(&"z").count());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Graph<N, E = ()> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    edges: Vec<Vec<(usize, E)>>,
+}
+
+const UNVISITED: usize = usize::MAX;
+
+impl<N, E> Graph<N, E>
+where
+    N: Eq + Hash + Clone,
+{
+    /// Returns a new, empty Graph.
+    #[inline]
+    pub fn new() -> Graph<N, E> {
+        Graph {
+            nodes: Vec::new(),
+            indices: HashMap::new(),
+            edges: Vec::new(),
+        }
+    }
+
+    /// Adds `node` to the graph if it is not in there yet, and returns its index.
+    #[inline]
+    pub fn add_node(&mut self, node: N) -> usize {
+        if let Some(&idx) = self.indices.get(&node) {
+            return idx;
+        }
+        let idx = self.nodes.len();
+        self.indices.insert(node.clone(), idx);
+        self.nodes.push(node);
+        self.edges.push(Vec::new());
+        idx
+    }
+
+    /// Adds an edge from `from` to `to`, adding the nodes if needed.
+    #[inline]
+    pub fn add_edge(&mut self, from: N, to: N, weight: E) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, weight));
+    }
+
+    /// Adds an edge from `a` to `b`, and one from `b` to `a`.
+    #[inline]
+    pub fn add_undirected_edge(&mut self, a: N, b: N, weight: E)
+    where
+        E: Clone,
+    {
+        self.add_edge(a.clone(), b.clone(), weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    /// Returns the number of nodes.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns the number of directed edges. An undirected edge counts twice.
+    #[inline]
+    pub fn edge_count(&self) -> usize {
+        self.edges.iter().map(Vec::len).sum()
+    }
+
+    /// Returns the index of `node`, if it is in the graph.
+    #[inline]
+    pub fn index_of(&self, node: &N) -> Option<usize> {
+        self.indices.get(node).cloned()
+    }
+
+    /// Returns an iterator over all nodes, in the order they were added.
+    #[inline]
+    pub fn nodes(&self) -> slice::Iter<'_, N> {
+        self.nodes.iter()
+    }
+
+    /// Returns an iterator over the nodes `node` has an edge to, together with the weight of that edge.
+    /// If `node` is not in the graph, the iterator is empty.
+    #[inline]
+    pub fn neighbours(&self, node: &N) -> Neighbours<'_, N, E> {
+        let edges = match self.indices.get(node) {
+            Some(&idx) => &self.edges[idx][..],
+            None => &[],
+        };
+        Neighbours {
+            nodes: &self.nodes,
+            edges: edges.iter(),
+        }
+    }
+
+    /// Sorts the nodes so that every edge points from an earlier node to a later one.
+    /// Nodes without an ordering between them keep the order they were added in.
+    /// Returns an error if the graph contains a cycle.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::graph::Graph;
+    /// fn main() {
+    ///     let mut graph: Graph<String> = Graph::parse_adjacency("shirt -> tie, belt\ntie -> jacket\nbelt -> jacket").unwrap();
+    ///     let sorted: Vec<&str> = graph.topological_sort().unwrap().into_iter().map(|s| s.as_str()).collect();
+    ///     assert_eq!(vec!["shirt", "tie", "belt", "jacket"], sorted);
+    ///     assert!(!graph.has_cycle());
+    ///
+    ///     graph.add_edge(String::from("jacket"), String::from("shirt"), ());
+    ///     assert!(graph.topological_sort().is_err());
+    ///     assert!(graph.has_cycle());
+    /// }
+    /// ```
+    pub fn topological_sort(&self) -> Result<Vec<&N>, CycleError> {
+        let mut indegree = vec![0; self.nodes.len()];
+        for &(to, _) in self.edges.iter().flat_map(|edges| edges.iter()) {
+            indegree[to] += 1;
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&idx| indegree[idx] == 0)
+            .collect();
+        let mut sorted = Vec::with_capacity(self.nodes.len());
+
+        while let Some(idx) = queue.pop_front() {
+            sorted.push(&self.nodes[idx]);
+            for &(to, _) in &self.edges[idx] {
+                indegree[to] -= 1;
+                if indegree[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        if sorted.len() == self.nodes.len() {
+            Ok(sorted)
+        } else {
+            Err(CycleError)
+        }
+    }
+
+    /// Returns whether following the edges, in their direction, can lead back to the node that was started from.
+    /// Every undirected edge forms such a cycle, so for graphs made with [add_undirected_edge](#method.add_undirected_edge)
+    /// or [parse_edges](#method.parse_edges), use [has_undirected_cycle](#method.has_undirected_cycle) instead.
+    #[inline]
+    pub fn has_cycle(&self) -> bool {
+        self.topological_sort().is_err()
+    }
+
+    /// Returns whether the graph contains a cycle when the direction of the edges is ignored, using union-find.
+    /// All edges between the same two nodes count as a single edge, so an undirected edge is not a cycle by itself.
+    /// An edge from a node to itself is a cycle.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::graph::Graph;
+    /// fn main() {
+    ///     let mut graph: Graph<char> = Graph::parse_edges("a-b\nb-c").unwrap();
+    ///     assert!(graph.has_cycle());
+    ///     assert!(!graph.has_undirected_cycle());
+    ///
+    ///     graph.add_edge('c', 'a', ());
+    ///     assert!(graph.has_undirected_cycle());
+    /// }
+    /// ```
+    pub fn has_undirected_cycle(&self) -> bool {
+        let mut parents: Vec<usize> = (0..self.nodes.len()).collect();
+        let mut seen = HashSet::new();
+
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                if from == to {
+                    return true;
+                }
+                if !seen.insert((from.min(to), from.max(to))) {
+                    continue;
+                }
+
+                let (a, b) = (find_root(&mut parents, from), find_root(&mut parents, to));
+                if a == b {
+                    return true;
+                }
+                parents[a] = b;
+            }
+        }
+        false
+    }
+
+    /// Returns the groups of nodes that are connected to each other, ignoring the direction of the edges.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::graph::Graph;
+    /// fn main() {
+    ///     let graph: Graph<u32, ()> = Graph::parse_edges("1-2\n3-4\n2-5").unwrap();
+    ///     assert_eq!(vec![vec![&1, &2, &5], vec![&3, &4]], graph.connected_components());
+    /// }
+    /// ```
+    pub fn connected_components(&self) -> Vec<Vec<&N>> {
+        let mut undirected = vec![Vec::new(); self.nodes.len()];
+        for (from, edges) in self.edges.iter().enumerate() {
+            for &(to, _) in edges {
+                undirected[from].push(to);
+                undirected[to].push(from);
+            }
+        }
+
+        let mut seen = vec![false; self.nodes.len()];
+        let mut components = Vec::new();
+        for root in 0..self.nodes.len() {
+            if seen[root] {
+                continue;
+            }
+            seen[root] = true;
+
+            let mut component = vec![root];
+            let mut next = 0;
+            while next < component.len() {
+                let idx = component[next];
+                next += 1;
+                for &other in &undirected[idx] {
+                    if !seen[other] {
+                        seen[other] = true;
+                        component.push(other);
+                    }
+                }
+            }
+            component.sort_unstable();
+            components.push(component.into_iter().map(|idx| &self.nodes[idx]).collect());
+        }
+        components
+    }
+
+    /// Returns the groups of nodes where every node can reach every other node of the group, using Tarjan's algorithm.
+    /// The groups are returned in reverse topological order: no group has an edge to a group that comes after it.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::graph::Graph;
+    /// fn main() {
+    ///     let graph: Graph<char, ()> = Graph::parse_adjacency("a -> b\nb -> c, d\nc -> a\nd ->").unwrap();
+    ///     assert_eq!(vec![vec![&'d'], vec![&'a', &'b', &'c']], graph.strongly_connected_components());
+    /// }
+    /// ```
+    pub fn strongly_connected_components(&self) -> Vec<Vec<&N>> {
+        let count = self.nodes.len();
+        let mut index = vec![UNVISITED; count];
+        let mut lowlink = vec![0; count];
+        let mut on_stack = vec![false; count];
+        let mut stack = Vec::new();
+        let mut next_index = 0;
+        let mut components = Vec::new();
+
+        // (node, position in its edge list) pairs, replacing the recursion of the textbook version.
+        let mut calls: Vec<(usize, usize)> = Vec::new();
+
+        for root in 0..count {
+            if index[root] != UNVISITED {
+                continue;
+            }
+
+            index[root] = next_index;
+            lowlink[root] = next_index;
+            next_index += 1;
+            stack.push(root);
+            on_stack[root] = true;
+            calls.push((root, 0));
+
+            while let Some(frame) = calls.last_mut() {
+                let (node, edge) = *frame;
+                if edge < self.edges[node].len() {
+                    frame.1 += 1;
+                    let to = self.edges[node][edge].0;
+                    if index[to] == UNVISITED {
+                        index[to] = next_index;
+                        lowlink[to] = next_index;
+                        next_index += 1;
+                        stack.push(to);
+                        on_stack[to] = true;
+                        calls.push((to, 0));
+                    } else if on_stack[to] {
+                        lowlink[node] = lowlink[node].min(index[to]);
+                    }
+                    continue;
+                }
+
+                calls.pop();
+                if let Some(&(parent, _)) = calls.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[node]);
+                }
+
+                if lowlink[node] == index[node] {
+                    let mut component = Vec::new();
+                    while let Some(member) = stack.pop() {
+                        on_stack[member] = false;
+                        component.push(member);
+                        if member == node {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    components.push(component.into_iter().map(|idx| &self.nodes[idx]).collect());
+                }
+            }
+        }
+        components
+    }
+}
+
+impl<N, E> Default for Graph<N, E>
+where
+    N: Eq + Hash + Clone,
+{
+    #[inline]
+    fn default() -> Graph<N, E> {
+        Graph::new()
+    }
+}
+
+impl<N> Graph<N, ()>
+where
+    N: Eq + Hash + Clone + FromStr,
+{
+    /// Parses lines like `"A -> B, C"` into a directed graph, with an edge from `A` to both `B` and `C`.
+    /// A line with nothing after the arrow only adds the node. Blank lines are skipped.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::graph::{Graph, ParseGraphError};
+    /// fn main() {
+    ///     let graph: Graph<u8> = Graph::parse_adjacency("1 -> 2, 3\n2 -> 3").unwrap();
+    ///     assert_eq!(3, graph.edge_count());
+    ///
+    ///     match Graph::<u8>::parse_adjacency("1 -> 2\n2 => 3") {
+    ///         Err(ParseGraphError::Syntax { line, .. }) => assert_eq!(2, line),
+    ///         _ => panic!(),
+    ///     }
+    /// }
+    /// ```
+    pub fn parse_adjacency(s: &str) -> Result<Graph<N, ()>, ParseGraphError<N::Err>> {
+        let mut graph = Graph::new();
+        for (line_idx, line) in s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+        {
+            let line_nr = line_idx + 1;
+            let mut parts = line.splitn(2, "->");
+            let (from, to) = match (parts.next(), parts.next()) {
+                (Some(from), Some(to)) => (from, to),
+                _ => return Err(ParseGraphError::syntax(line_nr, line)),
+            };
+
+            let from = graph.add_node(parse_node(line_nr, from)?);
+            let to = to.trim();
+            if to.is_empty() {
+                continue;
+            }
+            let to: Vec<N> = to
+                .split(',')
+                .map(str::trim)
+                .try_convert()
+                .map_err(|error| ParseGraphError::Node {
+                    line: line_nr,
+                    error,
+                })?;
+            for node in to {
+                let to = graph.add_node(node);
+                graph.edges[from].push((to, ()));
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Parses lines like `"A-B"` into an undirected graph, with an edge between `A` and `B`. Blank lines are skipped.
+    /// A `-` at the start of a node is part of that node, so negative numbers work.
+    /// For nodes that contain a `-` themselves, use [parse_edges_with](#method.parse_edges_with).
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::graph::Graph;
+    /// fn main() {
+    ///     let graph: Graph<i32> = Graph::parse_edges("-1-2\n2--3").unwrap();
+    ///     assert_eq!(vec![&-1, &2, &-3], graph.nodes().collect::<Vec<_>>());
+    ///     assert_eq!(4, graph.edge_count());
+    /// }
+    /// ```
+    #[inline]
+    pub fn parse_edges(s: &str) -> Result<Graph<N, ()>, ParseGraphError<N::Err>> {
+        Graph::parse_edges_with(s, "-")
+    }
+
+    /// Parses lines like `"A <-> B"` into an undirected graph, with an edge between `A` and `B`, where `separator` is what sits between the nodes.
+    /// Blank lines are skipped, and a separator at the start of a node is part of that node.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::graph::{Graph, ParseGraphError};
+    /// fn main() {
+    ///     let graph: Graph<String> = Graph::parse_edges_with("start-a <-> b-end", " <-> ").unwrap();
+    ///     assert_eq!(vec!["start-a", "b-end"], graph.nodes().collect::<Vec<_>>());
+    ///
+    ///     match Graph::<String>::parse_edges_with("a - b", " <-> ") {
+    ///         Err(ParseGraphError::Syntax { line, .. }) => assert_eq!(1, line),
+    ///         _ => panic!(),
+    ///     }
+    /// }
+    /// ```
+    pub fn parse_edges_with(
+        s: &str,
+        separator: &str,
+    ) -> Result<Graph<N, ()>, ParseGraphError<N::Err>> {
+        let mut graph = Graph::new();
+        for (line_idx, line) in s
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+        {
+            let line_nr = line_idx + 1;
+            let trimmed = line.trim_start();
+            // skip the first char, so a node may start with the separator.
+            let split = trimmed
+                .char_indices()
+                .nth(1)
+                .and_then(|(start, _)| trimmed[start..].find(separator).map(|idx| start + idx));
+            match split {
+                Some(idx) => {
+                    let a = parse_node(line_nr, &trimmed[..idx])?;
+                    let b = parse_node(line_nr, &trimmed[idx + separator.len()..])?;
+                    graph.add_undirected_edge(a, b, ());
+                }
+                None => return Err(ParseGraphError::syntax(line_nr, line)),
+            }
+        }
+        Ok(graph)
+    }
+}
+
+/// Returns the root of the set `node` belongs to, halving the path on the way.
+#[inline]
+fn find_root(parents: &mut [usize], mut node: usize) -> usize {
+    while parents[node] != node {
+        parents[node] = parents[parents[node]];
+        node = parents[node];
+    }
+    node
+}
+
+#[inline]
+fn parse_node<N: FromStr>(line: usize, s: &str) -> Result<N, ParseGraphError<N::Err>> {
+    s.trim()
+        .parse()
+        .map_err(|error| ParseGraphError::Node { line, error })
+}
+
+/// The Iterator that gets returned from [neighbours()](struct.Graph.html#method.neighbours)
+#[derive(Debug, Clone)]
+pub struct Neighbours<'a, N: 'a, E: 'a> {
+    nodes: &'a [N],
+    edges: slice::Iter<'a, (usize, E)>,
+}
+
+impl<'a, N, E> Iterator for Neighbours<'a, N, E> {
+    type Item = (&'a N, &'a E);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let nodes = self.nodes;
+        self.edges
+            .next()
+            .map(|&(to, ref weight)| (&nodes[to], weight))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.edges.size_hint()
+    }
+}
+
+/// The error returned by [topological_sort()](struct.Graph.html#method.topological_sort) when the graph contains a cycle.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct CycleError;
+
+impl Display for CycleError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "the graph contains a cycle")
+    }
+}
+
+impl Error for CycleError {}
+
+/// The error returned when parsing a graph fails. Lines are counted from 1.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ParseGraphError<E> {
+    /// The line does not look like an edge.
+    Syntax { line: usize, text: String },
+    /// A node could not be parsed.
+    Node { line: usize, error: E },
+}
+
+impl<E> ParseGraphError<E> {
+    #[inline]
+    fn syntax(line: usize, text: &str) -> ParseGraphError<E> {
+        ParseGraphError::Syntax {
+            line,
+            text: text.to_string(),
+        }
+    }
+}
+
+impl<E: Display> Display for ParseGraphError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            ParseGraphError::Syntax { line, ref text } => {
+                write!(f, "line {} is not an edge: {:?}", line, text)
+            }
+            ParseGraphError::Node { line, ref error } => {
+                write!(f, "invalid node on line {}: {}", line, error)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseGraphError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseGraphError::Syntax { .. } => None,
+            ParseGraphError::Node { ref error, .. } => Some(error),
+        }
+    }
+}
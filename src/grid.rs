@@ -0,0 +1,486 @@
+use convert::FromChar;
+use movement::Position;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::iter::StepBy;
+use std::ops::{Index, IndexMut};
+use std::slice;
+use std::str::FromStr;
+
+/// A dense 2D map, stored row by row in a single `Vec<T>`.
+/// `x` is the column and `y` is the row, so `Position::new(0, 0)` is the top-left cell.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::grid::Grid;
+/// use libaoc::movement::Position;
+/// fn main() {
+///     let mut grid = Grid::from_vec(3, vec![1, 2, 3, 4, 5, 6]).unwrap();
+///
+///     assert_eq!(3, grid.width());
+///     assert_eq!(2, grid.height());
+///     assert_eq!(6, grid[Position::new(2, 1)]);
+///
+///     grid[Position::new(0, 0)] = 9;
+///     assert_eq!(Some(&9), grid.get(Position::new(0, 0)));
+///     assert_eq!(None, grid.get(Position::new(-1, 0)));
+///     assert_eq!(None, grid.get(Position::new(3, 0)));
+///
+///     assert_eq!("923\n456", grid.to_string());
+/// }
+/// ```
+#[derive(Debug, Eq, PartialEq, Clone, Hash)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// Returns a new Grid of `width` by `height`, where every cell is a clone of `fill`.
+    /// A grid without columns has no rows either, so a `width` of 0 gives a height of 0.
+    #[inline]
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let height = if width == 0 { 0 } else { height };
+        Grid {
+            data: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    /// Builds a Grid out of a row-major `Vec<T>`.
+    /// Returns `None` if the length of `data` is not a multiple of `width`.
+    #[inline]
+    pub fn from_vec(width: usize, data: Vec<T>) -> Option<Grid<T>> {
+        match width {
+            0 if data.is_empty() => Some(Grid {
+                data,
+                width: 0,
+                height: 0,
+            }),
+            0 => None,
+            _ => match data.len() % width {
+                0 => Some(Grid {
+                    height: data.len() / width,
+                    data,
+                    width,
+                }),
+                _ => None,
+            },
+        }
+    }
+
+    /// Parses a character map into a Grid, converting every char with [FromChar](../convert/trait.FromChar.html).
+    /// Every line is a row. All rows must have the same length.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::convert::FromCharError;
+    /// use libaoc::grid::{Grid, ParseGridError};
+    /// use libaoc::movement::Position;
+    /// fn main() {
+    ///     let grid = Grid::<u8>::parse("123\n456\n").unwrap();
+    ///     assert_eq!(5, grid[Position::new(1, 1)]);
+    ///
+    ///     let walls: Grid<bool> = "#.\n.#".parse().unwrap();
+    ///     assert_eq!(true, walls[Position::new(1, 1)]);
+    ///
+    ///     assert_eq!(
+    ///         Err(ParseGridError::InvalidChar {
+    ///             row: 1,
+    ///             column: 2,
+    ///             character: 'x',
+    ///             error: FromCharError('x'),
+    ///         }),
+    ///         Grid::<u8>::parse("123\n45x")
+    ///     );
+    ///
+    ///     assert_eq!(
+    ///         Err(ParseGridError::RaggedRow { row: 1, expected: 3, found: 2 }),
+    ///         Grid::<u8>::parse("123\n45")
+    ///     );
+    /// }
+    /// ```
+    pub fn parse(s: &str) -> Result<Grid<T>, ParseGridError<T::Err>>
+    where
+        T: FromChar,
+    {
+        let mut data = Vec::with_capacity(s.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in s.lines().enumerate() {
+            let before = data.len();
+            for (column, character) in line.chars().enumerate() {
+                match T::from_char(character) {
+                    Ok(cell) => data.push(cell),
+                    Err(error) => {
+                        return Err(ParseGridError::InvalidChar {
+                            row,
+                            column,
+                            character,
+                            error,
+                        })
+                    }
+                }
+            }
+
+            let found = data.len() - before;
+            match width {
+                None => width = Some(found),
+                Some(expected) if expected != found => {
+                    return Err(ParseGridError::RaggedRow {
+                        row,
+                        expected,
+                        found,
+                    })
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        let width = width.unwrap_or(0);
+        Ok(Grid {
+            data,
+            width,
+            height: if width == 0 { 0 } else { height },
+        })
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cells as a row-major slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Returns whether `position` lies within the grid.
+    #[inline]
+    pub fn contains(&self, position: Position<isize>) -> bool {
+        self.flat_index(position).is_some()
+    }
+
+    /// Returns a reference to the cell at `position`, or `None` if it lies outside the grid.
+    #[inline]
+    pub fn get(&self, position: Position<isize>) -> Option<&T> {
+        self.flat_index(position).map(|idx| &self.data[idx])
+    }
+
+    /// Returns a mutable reference to the cell at `position`, or `None` if it lies outside the grid.
+    #[inline]
+    pub fn get_mut(&mut self, position: Position<isize>) -> Option<&mut T> {
+        match self.flat_index(position) {
+            Some(idx) => Some(&mut self.data[idx]),
+            None => None,
+        }
+    }
+
+    /// Returns an iterator over the rows of the grid, from top to bottom.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::grid::Grid;
+    /// fn main() {
+    ///     let grid = Grid::from_vec(2, vec!['a', 'b', 'c', 'd']).unwrap();
+    ///     let mut rows = grid.rows();
+    ///
+    ///     assert_eq!(Some(&['a', 'b'][..]), rows.next());
+    ///     assert_eq!(Some(&['c', 'd'][..]), rows.next());
+    ///     assert_eq!(None, rows.next());
+    /// }
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> Rows<'_, T> {
+        // `chunks` panics on a chunk size of 0, an empty grid just has no rows.
+        self.data.chunks(self.width.max(1))
+    }
+
+    /// Returns an iterator over the columns of the grid, from left to right.
+    /// Every column is itself an iterator over the cells in that column, from top to bottom.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::grid::Grid;
+    /// fn main() {
+    ///     let grid = Grid::from_vec(2, vec!['a', 'b', 'c', 'd']).unwrap();
+    ///     let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+    ///
+    ///     assert_eq!(vec!["ac", "bd"], columns);
+    /// }
+    /// ```
+    #[inline]
+    pub fn columns(&self) -> Columns<'_, T> {
+        Columns {
+            grid: self,
+            column: 0,
+        }
+    }
+
+    /// Returns an iterator over the positions directly above, below, left and right of `position`,
+    /// skipping those that lie outside the grid.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::grid::Grid;
+    /// use libaoc::movement::Position;
+    /// fn main() {
+    ///     let grid = Grid::new(3, 3, '.');
+    ///
+    ///     let corner: Vec<_> = grid.neighbours(Position::new(0, 0)).collect();
+    ///     assert_eq!(vec![Position::new(1, 0), Position::new(0, 1)], corner);
+    ///
+    ///     assert_eq!(4, grid.neighbours(Position::new(1, 1)).count());
+    /// }
+    /// ```
+    #[inline]
+    pub fn neighbours(&self, position: Position<usize>) -> Neighbours {
+        Neighbours::new(position, self.width, self.height, &ORTHOGONAL)
+    }
+
+    /// Same as [`neighbours`], but also includes the diagonally adjecent positions.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::grid::Grid;
+    /// use libaoc::movement::Position;
+    /// fn main() {
+    ///     let grid = Grid::new(3, 3, '.');
+    ///
+    ///     assert_eq!(3, grid.neighbours8(Position::new(2, 2)).count());
+    ///     assert_eq!(8, grid.neighbours8(Position::new(1, 1)).count());
+    /// }
+    /// ```
+    ///
+    /// [`neighbours`]: #method.neighbours
+    #[inline]
+    pub fn neighbours8(&self, position: Position<usize>) -> Neighbours {
+        Neighbours::new(position, self.width, self.height, &ALL_AROUND)
+    }
+
+    #[inline]
+    fn flat_index(&self, position: Position<isize>) -> Option<usize> {
+        let (&x, &y) = position.get_ref();
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            None
+        } else {
+            Some(y as usize * self.width + x as usize)
+        }
+    }
+
+    #[inline]
+    fn checked_flat_index(&self, position: &Position<usize>) -> usize {
+        let (&x, &y) = position.get_ref();
+        assert!(
+            x < self.width && y < self.height,
+            "position {} is out of bounds for a grid of {}x{}",
+            position,
+            self.width,
+            self.height
+        );
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Position<usize>> for Grid<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, position: Position<usize>) -> &T {
+        let idx = self.checked_flat_index(&position);
+        &self.data[idx]
+    }
+}
+
+impl<T> IndexMut<Position<usize>> for Grid<T> {
+    #[inline]
+    fn index_mut(&mut self, position: Position<usize>) -> &mut T {
+        let idx = self.checked_flat_index(&position);
+        &mut self.data[idx]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        for (idx, row) in self.rows().enumerate() {
+            if idx != 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The Iterator that gets returned from [rows()](struct.Grid.html#method.rows)
+pub type Rows<'a, T> = slice::Chunks<'a, T>;
+
+/// An iterator over the cells of a single column.
+pub type Column<'a, T> = StepBy<slice::Iter<'a, T>>;
+
+/// The Iterator that gets returned from [columns()](struct.Grid.html#method.columns)
+#[derive(Debug, Clone)]
+pub struct Columns<'a, T: 'a> {
+    grid: &'a Grid<T>,
+    column: usize,
+}
+
+impl<'a, T> Iterator for Columns<'a, T> {
+    type Item = Column<'a, T>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.column >= self.grid.width {
+            return None;
+        }
+        let column = self.grid.data[self.column..]
+            .iter()
+            .step_by(self.grid.width);
+        self.column += 1;
+        Some(column)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.grid.width - self.column;
+        (remaining, Some(remaining))
+    }
+}
+
+const ORTHOGONAL: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+const ALL_AROUND: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// The Iterator that gets returned from [neighbours()](struct.Grid.html#method.neighbours) and [neighbours8()](struct.Grid.html#method.neighbours8)
+#[derive(Debug, Clone)]
+pub struct Neighbours {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+    offsets: slice::Iter<'static, (isize, isize)>,
+}
+
+impl Neighbours {
+    #[inline]
+    fn new(
+        position: Position<usize>,
+        width: usize,
+        height: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> Neighbours {
+        let (x, y) = position.cpy_into_tuple();
+        Neighbours {
+            x,
+            y,
+            width,
+            height,
+            offsets: offsets.iter(),
+        }
+    }
+}
+
+impl Iterator for Neighbours {
+    type Item = Position<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        for &(dx, dy) in &mut self.offsets {
+            let x = self.x.wrapping_add(dx as usize);
+            let y = self.y.wrapping_add(dy as usize);
+            if x < self.width && y < self.height {
+                return Some(Position::new(x, y));
+            }
+        }
+        None
+    }
+}
+
+impl<T: FromChar> FromStr for Grid<T> {
+    type Err = ParseGridError<T::Err>;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Grid<T>, Self::Err> {
+        Grid::parse(s)
+    }
+}
+
+/// The error returned by [Grid::parse](struct.Grid.html#method.parse).
+/// Rows and columns are counted from 0.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ParseGridError<E> {
+    /// A char could not be converted into a cell.
+    InvalidChar {
+        row: usize,
+        column: usize,
+        character: char,
+        error: E,
+    },
+    /// A row had a different length than the first row.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl<E: Display> Display for ParseGridError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            ParseGridError::InvalidChar {
+                row,
+                column,
+                character,
+                ref error,
+            } => write!(
+                f,
+                "invalid character {:?} at row {}, column {}: {}",
+                character, row, column, error
+            ),
+            ParseGridError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "row {} has {} columns, expected {}",
+                row, found, expected
+            ),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseGridError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseGridError::InvalidChar { ref error, .. } => Some(error),
+            ParseGridError::RaggedRow { .. } => None,
+        }
+    }
+}
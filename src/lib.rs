@@ -2,20 +2,35 @@
 //!
 //! This library has a trait for converting Iterators,
 //! a struct and an enum for keeping track of a Position and a Direction,
-//! a trait for calculating the `manhatten-distance`,
-//! a trait implemented on all integers, that allow to get the absolute value of that integer,
+//! a dense Grid to store a map in,
+//! breadth-first, Dijkstra and A* searches to find the shortest path through such a map,
+//! a Graph that can be parsed from a list of edges,
+//! a Runner that runs and times the solution of every day,
+//! a scan! macro that parses a line according to a template, traits that take all integers from a line or split an input on blank lines,
+//! a parse_struct! macro that implements `FromStr` for a struct of delimited fields,
+//! metrics for the Manhattan, Chebyshev and Euclidean distance between positions,
+//! traits implemented on all integers, that allow to get the absolute value of that integer, or the difference between two of them,
+//! gcd, lcm, modular arithmetic and the Chinese Remainder Theorem on all integers,
 //! and a trait for quickly sorting a tuple in ascending or descending order.
 //!
-//! Also supports reading tekst from a file into a String, or Vec<u8>, however this is a feature of this library, and is considered unstable.
-#![cfg_attr(feature = "nightly", feature(try_from))]
+//! Also supports reading puzzle input from a file or stdin, into a String, raw bytes, or parsed values, either at once or line by line.
+//! Every error of this crate converts into [Error](enum.Error.html), so `?` works across all of it.
 
 #[macro_use]
 pub mod convert;
 pub mod absolute;
+pub mod error;
+pub mod graph;
+pub mod grid;
+pub mod math;
 pub mod movement;
-
-#[cfg(feature = "readfile")]
+#[macro_use]
+pub mod parse;
+pub mod pathfinding;
 pub mod reading;
+pub mod runner;
+
+pub use error::{Error, Result};
 
 #[cfg(test)]
 mod tests;
@@ -200,6 +215,9 @@ where
 /// An easy way to combine the solutions of the problems.
 /// When this macro is called, a macro-name, day, year and implementation must be given.
 /// This macro then creates a macro with the given name, running the implementation when called.
+///
+/// Prefer the [runner](runner/index.html) module, which returns the answers, times them, and can select what to run.
+#[deprecated(note = "implement `runner::Solution` and register it with a `runner::Runner` instead")]
 #[macro_export]
 macro_rules! aoc {
     ($day_name:ident, $day:expr, $year:expr, $implementation:block) => {
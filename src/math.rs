@@ -0,0 +1,441 @@
+//! Number theory on any integer: gcd, lcm, modular arithmetic and the Chinese Remainder Theorem.
+//!
+//! Everything is computed with 128-bit intermediates, so no function overflows halfway.
+//! Only a result that does not fit in the integer type is reported, by a panic or by returning `None`.
+use std::convert::TryFrom;
+
+/// An integer, that can be converted to and from the 128-bit integers that the functions of this module compute with.
+/// Implemented on all integers.
+pub trait Integer: Copy + Ord {
+    /// Returns self as an i128, or `None` if it does not fit.
+    fn to_i128(self) -> Option<i128>;
+
+    /// Returns `n` as Self, or `None` if it does not fit.
+    fn from_i128(n: i128) -> Option<Self>;
+
+    /// Returns the absolute value of self as an u128. This never overflows.
+    fn unsigned_abs_u128(self) -> u128;
+
+    /// Returns `n` as Self, or `None` if it does not fit.
+    fn from_u128(n: u128) -> Option<Self>;
+
+    /// Returns whether self is less than zero.
+    fn is_negative(self) -> bool;
+}
+
+macro_rules! signed_integer {
+    ($($int:ty),+) => {
+        $(
+            impl Integer for $int {
+                #[inline(always)]
+                fn to_i128(self) -> Option<i128> {
+                    Some(self as i128)
+                }
+
+                #[inline(always)]
+                fn from_i128(n: i128) -> Option<Self> {
+                    <$int>::try_from(n).ok()
+                }
+
+                #[inline(always)]
+                fn unsigned_abs_u128(self) -> u128 {
+                    (self as i128).unsigned_abs()
+                }
+
+                #[inline(always)]
+                fn from_u128(n: u128) -> Option<Self> {
+                    <$int>::try_from(n).ok()
+                }
+
+                #[inline(always)]
+                fn is_negative(self) -> bool {
+                    self < 0
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! unsigned_integer {
+    ($($int:ty),+) => {
+        $(
+            impl Integer for $int {
+                #[inline(always)]
+                fn to_i128(self) -> Option<i128> {
+                    i128::try_from(self).ok()
+                }
+
+                #[inline(always)]
+                fn from_i128(n: i128) -> Option<Self> {
+                    <$int>::try_from(n).ok()
+                }
+
+                #[inline(always)]
+                fn unsigned_abs_u128(self) -> u128 {
+                    self as u128
+                }
+
+                #[inline(always)]
+                fn from_u128(n: u128) -> Option<Self> {
+                    <$int>::try_from(n).ok()
+                }
+
+                #[inline(always)]
+                fn is_negative(self) -> bool {
+                    false
+                }
+            }
+        )+
+    };
+}
+
+signed_integer!(i128, i64, i32, i16, i8, isize);
+unsigned_integer!(u128, u64, u32, u16, u8, usize);
+
+/// Returns `n` as an i128.
+/// # Panics
+/// Panics if `n` does not fit, which can only happen for an u128 above `i128::MAX`.
+#[inline]
+fn wide<T: Integer>(n: T) -> i128 {
+    n.to_i128().expect("the number does not fit in an i128")
+}
+
+/// Returns a positive `modulus` as an u128.
+/// # Panics
+/// Panics if `modulus` is not positive.
+#[inline]
+fn positive_modulus<T: Integer>(modulus: T) -> u128 {
+    assert!(
+        !modulus.is_negative() && modulus.unsigned_abs_u128() != 0,
+        "the modulus must be positive"
+    );
+    modulus.unsigned_abs_u128()
+}
+
+/// Returns `n mod m`, in `0..m`.
+#[inline]
+fn rem_euclid<T: Integer>(n: T, m: u128) -> u128 {
+    let rem = n.unsigned_abs_u128() % m;
+    if n.is_negative() && rem != 0 {
+        m - rem
+    } else {
+        rem
+    }
+}
+
+/// Returns `(a + b) mod m`, for `a` and `b` in `0..m`.
+#[inline]
+fn add_mod(a: u128, b: u128, m: u128) -> u128 {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Returns `(a * b) mod m`, for `a` and `b` in `0..m`, without overflowing.
+#[inline]
+fn mul_mod(a: u128, b: u128, m: u128) -> u128 {
+    if m <= u128::from(u64::MAX) {
+        return a * b % m;
+    }
+
+    // double-and-add, since the product does not fit in an u128.
+    let (mut a, mut b, mut product) = (a, b, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            product = add_mod(product, a, m);
+        }
+        a = add_mod(a, a, m);
+        b >>= 1;
+    }
+    product
+}
+
+#[inline]
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let rem = a % b;
+        a = b;
+        b = rem;
+    }
+    a
+}
+
+/// Returns the greatest common divisor of `a` and `b`, which is never negative.
+/// `gcd(0, 0)` is 0.
+/// # Panics
+/// Panics if the result does not fit in T, like `gcd(i64::MIN, 0)`.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::gcd;
+/// fn main() {
+///     assert_eq!(6, gcd(12, 18));
+///     assert_eq!(6, gcd(-12i32, 18));
+///     assert_eq!(7u8, gcd(0, 7));
+///     assert_eq!(1 << 62, gcd(i64::MIN, 1 << 62));
+/// }
+/// ```
+#[inline]
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    T::from_u128(gcd_u128(a.unsigned_abs_u128(), b.unsigned_abs_u128()))
+        .expect("the gcd does not fit in the integer type")
+}
+
+/// Returns the least common multiple of `a` and `b`, which is never negative,
+/// or `None` if it does not fit in T. The lcm with 0 is 0.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::checked_lcm;
+/// fn main() {
+///     assert_eq!(Some(36), checked_lcm(12, 18));
+///     assert_eq!(Some(0), checked_lcm(0, 5));
+///     assert_eq!(None, checked_lcm(200u8, 3));
+/// }
+/// ```
+pub fn checked_lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    let (a, b) = (a.unsigned_abs_u128(), b.unsigned_abs_u128());
+    if a == 0 || b == 0 {
+        return T::from_u128(0);
+    }
+    (a / gcd_u128(a, b)).checked_mul(b).and_then(T::from_u128)
+}
+
+/// Returns the least common multiple of `a` and `b`, which is never negative.
+/// # Panics
+/// Panics if the result does not fit in T. Use [checked_lcm](fn.checked_lcm.html) to handle that.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::lcm;
+/// fn main() {
+///     assert_eq!(36, lcm(12, 18));
+///     assert_eq!(21, lcm(-3i64, 7));
+/// }
+/// ```
+#[inline]
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    checked_lcm(a, b).expect("the lcm does not fit in the integer type")
+}
+
+/// Returns the gcd of all numbers, or `None` if there are none.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::gcd_all;
+/// fn main() {
+///     assert_eq!(Some(4), gcd_all(vec![8, 12, 20]));
+///     assert_eq!(None, gcd_all(Vec::<u32>::new()));
+/// }
+/// ```
+#[inline]
+pub fn gcd_all<T, I>(numbers: I) -> Option<T>
+where
+    T: Integer,
+    I: IntoIterator<Item = T>,
+{
+    numbers.into_iter().fold(None, |acc, n| match acc {
+        Some(acc) => Some(gcd(acc, n)),
+        None => Some(gcd(n, n)),
+    })
+}
+
+/// Returns the lcm of all numbers, or `None` if there are none or the lcm does not fit in T.
+/// This is the usual way to find when a number of cycles line up again.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::lcm_all;
+/// fn main() {
+///     assert_eq!(Some(2520u64), lcm_all(1..=10));
+///     assert_eq!(None, lcm_all(1u8..=10));
+/// }
+/// ```
+#[inline]
+pub fn lcm_all<T, I>(numbers: I) -> Option<T>
+where
+    T: Integer,
+    I: IntoIterator<Item = T>,
+{
+    let mut numbers = numbers.into_iter();
+    let first = numbers.next()?;
+    numbers.try_fold(checked_lcm(first, first)?, checked_lcm)
+}
+
+/// The extended Euclidean algorithm.
+/// Returns `(g, x, y)`, where `g` is the gcd of `a` and `b`, and `a * x + b * y == g`.
+///
+/// Returns `None` if `g`, `x` or `y` does not fit in an i128, which can only happen if `a` or `b` is `i128::MIN`.
+/// # Panics
+/// Panics if `a` or `b` does not fit in an i128.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::extended_gcd;
+/// fn main() {
+///     let (g, x, y) = extended_gcd(240, 46).unwrap();
+///     assert_eq!(2, g);
+///     assert_eq!(2, 240 * x + 46 * y);
+///
+///     // the gcd would be 2^127.
+///     assert_eq!(None, extended_gcd(i128::MIN, 0));
+/// }
+/// ```
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> Option<(i128, i128, i128)> {
+    let (mut old_r, mut r) = (wide(a), wide(b));
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+
+    while r != 0 {
+        let quotient = old_r.checked_div(r)?;
+        let next_r = old_r.checked_sub(quotient.checked_mul(r)?)?;
+        old_r = r;
+        r = next_r;
+
+        // the coefficients of the last step are never returned, and do not have to fit.
+        let (next_x, next_y) = if r == 0 {
+            (0, 0)
+        } else {
+            (
+                old_x.checked_sub(quotient.checked_mul(x)?)?,
+                old_y.checked_sub(quotient.checked_mul(y)?)?,
+            )
+        };
+        old_x = x;
+        x = next_x;
+        old_y = y;
+        y = next_y;
+    }
+
+    if old_r < 0 {
+        Some((
+            old_r.checked_neg()?,
+            old_x.checked_neg()?,
+            old_y.checked_neg()?,
+        ))
+    } else {
+        Some((old_r, old_x, old_y))
+    }
+}
+
+/// Returns `base` to the power `exp`, modulo `modulus`, in `0..modulus`.
+/// # Panics
+/// Panics if `exp` is negative, or `modulus` is not positive.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::mod_pow;
+/// fn main() {
+///     assert_eq!(445, mod_pow(4, 13, 497));
+///     assert_eq!(4, mod_pow(-3i32, 3, 31));
+///
+///     // the intermediate products do not fit in an u64.
+///     assert_eq!(1, mod_pow(3u64, 18446744073709551556, 18446744073709551557));
+/// }
+/// ```
+pub fn mod_pow<T: Integer>(base: T, exp: T, modulus: T) -> T {
+    let m = positive_modulus(modulus);
+    assert!(!exp.is_negative(), "the exponent must not be negative");
+
+    let mut base = rem_euclid(base, m);
+    let mut exp = exp.unsigned_abs_u128();
+    let mut result = 1 % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = mul_mod(result, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exp >>= 1;
+    }
+    // the result is less than the modulus, so it fits.
+    T::from_u128(result).unwrap()
+}
+
+/// Returns the modular inverse of `a`, in `0..modulus`, or `None` if `a` and `modulus` are not coprime.
+/// # Panics
+/// Panics if `modulus` is not positive, or does not fit in an i128.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::mod_inv;
+/// fn main() {
+///     assert_eq!(Some(4), mod_inv(3, 11));
+///     assert_eq!(Some(7), mod_inv(-3i8, 11));
+///     assert_eq!(None, mod_inv(4, 10));
+/// }
+/// ```
+pub fn mod_inv<T: Integer>(a: T, modulus: T) -> Option<T> {
+    let m = positive_modulus(modulus);
+    let m_wide = wide(modulus);
+    let a = rem_euclid(a, m) as i128;
+
+    // both numbers are in 0..=i128::MAX, so nothing overflows.
+    let (g, x, _) = extended_gcd(a, m_wide).unwrap();
+    if g != 1 {
+        return None;
+    }
+    T::from_i128(x.rem_euclid(m_wide))
+}
+
+/// The Chinese Remainder Theorem.
+/// Given `(residue, modulus)` pairs, returns `(r, m)` such that a number `n` is congruent to every residue modulo its modulus
+/// exactly if `n` is congruent to `r` modulo `m`. `m` is the lcm of the moduli, and `r` is in `0..m`.
+///
+/// The moduli do not have to be coprime. Returns `None` if the congruences contradict each other, if a modulus is not positive,
+/// or if `m` does not fit in T. Without any congruences, returns `(0, 1)`.
+/// # Panics
+/// Panics if a residue or modulus does not fit in an i128.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::math::crt;
+/// fn main() {
+///     assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+///
+///     // the moduli 4 and 6 share a factor 2.
+///     assert_eq!(Some((7, 12)), crt(&[(3i64, 4), (1, 6)]));
+///     assert_eq!(None, crt(&[(0i64, 4), (1, 6)]));
+///
+///     // a bus schedule: bus 7 leaves at t, bus 13 at t + 1, bus 59 at t + 4.
+///     assert_eq!(Some((350, 5369u64)), crt(&[(0, 7), (13 - 1, 13), (59 - 4, 59)]));
+/// }
+/// ```
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let (mut r1, mut m1): (u128, u128) = (0, 1);
+
+    for &(residue, modulus) in congruences {
+        if modulus.is_negative() || modulus.unsigned_abs_u128() == 0 {
+            return None;
+        }
+        let m2 = wide(modulus) as u128;
+        let r2 = rem_euclid(wide(residue), m2);
+
+        let g = gcd_u128(m1, m2);
+        let (diff, diff_negative) = if r2 >= r1 {
+            (r2 - r1, false)
+        } else {
+            (r1 - r2, true)
+        };
+        if diff % g != 0 {
+            return None;
+        }
+
+        // solve m1 * k == r2 - r1 (mod m2), which reduces to (m1 / g) * k == (r2 - r1) / g (mod m2 / g).
+        let reduced = m2 / g;
+        let inverse = mod_inv((m1 / g % reduced) as i128, reduced as i128)?;
+        let mut step = (diff / g) % reduced;
+        if diff_negative && step != 0 {
+            step = reduced - step;
+        }
+        let k = mul_mod(step, inverse as u128, reduced);
+
+        let m = (m1 / g).checked_mul(m2)?;
+        // m1 * k < m1 * (m2 / g) == m, so this does not overflow.
+        r1 = add_mod(r1 % m, m1 * k, m);
+        m1 = m;
+    }
+
+    Some((T::from_u128(r1)?, T::from_u128(m1)?))
+}
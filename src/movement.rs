@@ -1,6 +1,9 @@
-use absolute::Absolute;
+use absolute::{AbsDiff, Absolute};
+use std::convert::TryFrom;
+use std::error::Error;
 use std::fmt::{self, Display, Formatter};
-use std::ops::{Add, AddAssign, Sub, SubAssign};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Sub, SubAssign};
+use std::str::FromStr;
 
 /// An enum to represent a direction.
 /// Is great to use in maps, or when 'following' some kind of line.
@@ -79,7 +82,548 @@ impl Direction {
 
 impl Display for Direction {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        write!(f, "{}", self)
+        write!(f, "{:?}", self)
+    }
+}
+
+/// An enum to represent one of the eight compass directions.
+/// Use this instead of [Direction](enum.Direction.html) when diagonal moves are allowed.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::movement::{Direction, Direction8};
+/// use std::convert::TryFrom;
+/// fn main() {
+///     let dir = Direction8::N;
+///     assert_eq!(Direction8::NE, dir.turn_right());
+///     assert_eq!(Direction8::NW, dir.turn_left());
+///     assert_eq!(Direction8::S, dir.reverse());
+///
+///     assert_eq!(Direction8::W, Direction8::from(Direction::Left));
+///     assert_eq!(Ok(Direction::Up), Direction::try_from(Direction8::N));
+///     assert_eq!(Err(Direction8::SE), Direction::try_from(Direction8::SE));
+/// }
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    /// All eight directions, clockwise, starting at `N`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /// turns the direction 45 degrees to the right.
+    #[inline]
+    pub fn turn_right(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 1) % 8]
+    }
+
+    /// turns the direction 45 degrees to the left.
+    #[inline]
+    pub fn turn_left(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 7) % 8]
+    }
+
+    /// Reverses the current direction.
+    #[inline]
+    pub fn reverse(self) -> Direction8 {
+        Direction8::ALL[(self as usize + 4) % 8]
+    }
+
+    /// Returns whether the direction is one of the four diagonals.
+    #[inline]
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Splits the direction into its vertical and horizontal components.
+    #[inline]
+    fn cardinals(self) -> (Direction, Option<Direction>) {
+        match self {
+            Direction8::N => (Direction::Up, None),
+            Direction8::NE => (Direction::Up, Some(Direction::Right)),
+            Direction8::E => (Direction::Right, None),
+            Direction8::SE => (Direction::Down, Some(Direction::Right)),
+            Direction8::S => (Direction::Down, None),
+            Direction8::SW => (Direction::Down, Some(Direction::Left)),
+            Direction8::W => (Direction::Left, None),
+            Direction8::NW => (Direction::Up, Some(Direction::Left)),
+        }
+    }
+}
+
+impl Display for Direction8 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+impl From<Direction> for Direction8 {
+    #[inline]
+    fn from(direction: Direction) -> Direction8 {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Right => Direction8::E,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+        }
+    }
+}
+
+/// Fails on the diagonals, returning the diagonal as the error.
+impl TryFrom<Direction8> for Direction {
+    type Error = Direction8;
+
+    #[inline]
+    fn try_from(direction: Direction8) -> Result<Direction, Direction8> {
+        match direction {
+            Direction8::N => Ok(Direction::Up),
+            Direction8::E => Ok(Direction::Right),
+            Direction8::S => Ok(Direction::Down),
+            Direction8::W => Ok(Direction::Left),
+            diagonal => Err(diagonal),
+        }
+    }
+}
+
+/// An enum to represent a direction on a hexagonal grid with pointy-topped hexagons,
+/// where every hexagon has a neighbour to the east and to the west.
+///
+/// Positions on such a grid can either be stored in axial coordinates, using a [Position](struct.Position.html) with `x` as `q` and `y` as `r`,
+/// or in cube coordinates, using a [Position3](type.Position3.html) of `q`, `r` and `s`, where `q + r + s == 0`.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::movement::HexDirection;
+/// fn main() {
+///     let dir = HexDirection::E;
+///     assert_eq!(HexDirection::SE, dir.turn_right());
+///     assert_eq!(HexDirection::NE, dir.turn_left());
+///     assert_eq!(HexDirection::W, dir.reverse());
+/// }
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub enum HexDirection {
+    NE,
+    E,
+    SE,
+    SW,
+    W,
+    NW,
+}
+
+impl HexDirection {
+    /// All six directions, clockwise, starting at `NE`.
+    pub const ALL: [HexDirection; 6] = [
+        HexDirection::NE,
+        HexDirection::E,
+        HexDirection::SE,
+        HexDirection::SW,
+        HexDirection::W,
+        HexDirection::NW,
+    ];
+
+    /// turns the direction 60 degrees to the right.
+    #[inline]
+    pub fn turn_right(self) -> HexDirection {
+        HexDirection::ALL[(self as usize + 1) % 6]
+    }
+
+    /// turns the direction 60 degrees to the left.
+    #[inline]
+    pub fn turn_left(self) -> HexDirection {
+        HexDirection::ALL[(self as usize + 5) % 6]
+    }
+
+    /// Reverses the current direction.
+    #[inline]
+    pub fn reverse(self) -> HexDirection {
+        HexDirection::ALL[(self as usize + 3) % 6]
+    }
+}
+
+impl Display for HexDirection {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// Parses a direction from a single char.
+/// Accepts letters (`U`, `D`, `L`, `R`), compass points (`N`, `E`, `S`, `W`) in either case, and arrows (`^`, `v`, `<`, `>`).
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::convert::TryConvert;
+/// use libaoc::movement::{Direction, ParseDirectionError};
+/// use std::convert::TryFrom;
+/// fn main() {
+///     assert_eq!(Ok(Direction::Up), Direction::try_from('^'));
+///     assert_eq!(Ok(Direction::Left), "W".parse());
+///
+///     let dirs: Vec<Direction> = "R U d".split(' ').try_convert().unwrap();
+///     assert_eq!(vec![Direction::Right, Direction::Up, Direction::Down], dirs);
+///
+///     assert_eq!(
+///         Err(ParseDirectionError { token: String::from("x") }),
+///         "x".parse::<Direction>()
+///     );
+/// }
+/// ```
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    #[inline]
+    fn try_from(c: char) -> Result<Direction, ParseDirectionError> {
+        match c {
+            'U' | 'u' | 'N' | 'n' | '^' => Ok(Direction::Up),
+            'D' | 'd' | 'S' | 's' | 'v' => Ok(Direction::Down),
+            'R' | 'r' | 'E' | 'e' | '>' => Ok(Direction::Right),
+            'L' | 'l' | 'W' | 'w' | '<' => Ok(Direction::Left),
+            _ => Err(ParseDirectionError {
+                token: c.to_string(),
+            }),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Direction, ParseDirectionError> {
+        let token = s.trim();
+        let mut chars = token.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(ParseDirectionError {
+                token: token.to_string(),
+            }),
+        }
+    }
+}
+
+/// The error returned when a token can not be parsed into a [Direction](enum.Direction.html). Holds the unrecognised token.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Clone, Hash)]
+pub struct ParseDirectionError {
+    pub token: String,
+}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "unrecognised direction {:?}", self.token)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+/// A turn to the left or to the right.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub enum Turn {
+    Left,
+    Right,
+}
+
+impl Turn {
+    /// Turns `direction` this way.
+    #[inline]
+    pub fn apply(self, direction: Direction) -> Direction {
+        match self {
+            Turn::Left => direction.turn_left(),
+            Turn::Right => direction.turn_right(),
+        }
+    }
+}
+
+/// For puzzles where `L` and `R` are absolute directions instead of turns.
+impl From<Turn> for Direction {
+    #[inline]
+    fn from(turn: Turn) -> Direction {
+        match turn {
+            Turn::Left => Direction::Left,
+            Turn::Right => Direction::Right,
+        }
+    }
+}
+
+/// The first part of an [Instruction](struct.Instruction.html).
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub enum TurnOrDir {
+    /// `L` or `R`.
+    Turn(Turn),
+    /// Any other token accepted by [Direction](enum.Direction.html)'s `FromStr`.
+    Dir(Direction),
+    /// `F`.
+    Forward,
+}
+
+impl TurnOrDir {
+    /// Returns the direction to face after this instruction, when currently facing `facing`.
+    #[inline]
+    pub fn apply(self, facing: Direction) -> Direction {
+        match self {
+            TurnOrDir::Turn(turn) => turn.apply(facing),
+            TurnOrDir::Dir(direction) => direction,
+            TurnOrDir::Forward => facing,
+        }
+    }
+}
+
+/// A parsed `"R5"`, `"L90"`, `"U 3"` or `"F10"` style instruction, for puzzles that steer.
+/// `L` and `R` always parse as a [Turn](enum.Turn.html), `F` as forward, and anything else as a [Direction](enum.Direction.html).
+/// Whitespace between the heading and the steps is allowed.
+///
+/// For puzzles where `L` and `R` are absolute directions, like `"R 4"` next to `"U 4"`, parse a [Move](struct.Move.html) instead.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::convert::TryConvert;
+/// use libaoc::movement::{Direction, Instruction, ParseInstructionError, Position, Turn, TurnOrDir};
+/// fn main() {
+///     let instructions: Vec<Instruction<i32>> = "R2, L3".split(", ").try_convert().unwrap();
+///     assert_eq!(Instruction { turn_or_dir: TurnOrDir::Turn(Turn::Right), steps: 2 }, instructions[0]);
+///
+///     let mut facing = Direction::Up;
+///     let mut pos = Position::new(0, 0);
+///     for instruction in instructions {
+///         facing = instruction.turn_or_dir.apply(facing);
+///         pos.change(&facing, instruction.steps);
+///     }
+///     assert_eq!(Position::new(2, -3), pos);
+///
+///     assert_eq!(
+///         Ok(Instruction { turn_or_dir: TurnOrDir::Dir(Direction::Down), steps: 4 }),
+///         "v 4".parse()
+///     );
+///
+///     match "X5".parse::<Instruction<i32>>() {
+///         Err(ParseInstructionError::Heading(e)) => assert_eq!("X", e.token),
+///         _ => panic!(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct Instruction<N> {
+    pub turn_or_dir: TurnOrDir,
+    pub steps: N,
+}
+
+impl<N: FromStr> FromStr for Instruction<N> {
+    type Err = ParseInstructionError<N::Err>;
+
+    fn from_str(s: &str) -> Result<Instruction<N>, Self::Err> {
+        let (heading, steps) = split_instruction(s)?;
+        let turn_or_dir = match heading {
+            'L' | 'l' => TurnOrDir::Turn(Turn::Left),
+            'R' | 'r' => TurnOrDir::Turn(Turn::Right),
+            'F' | 'f' => TurnOrDir::Forward,
+            c => TurnOrDir::Dir(Direction::try_from(c).map_err(ParseInstructionError::Heading)?),
+        };
+
+        Ok(Instruction {
+            turn_or_dir,
+            steps: parse_steps(steps)?,
+        })
+    }
+}
+
+/// A parsed `"R 4"`, `"U4"` or `"> 2"` style move, for puzzles where every heading is an absolute direction.
+/// Unlike an [Instruction](struct.Instruction.html), `L` and `R` parse as `Direction::Left` and `Direction::Right`,
+/// and the heading accepts anything [Direction](enum.Direction.html)'s `FromStr` does.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::convert::TryConvert;
+/// use libaoc::movement::{Direction, Move, ParseInstructionError, Position};
+/// fn main() {
+///     let moves: Vec<Move<i32>> = "R 4\nU 4\nL 3".lines().try_convert().unwrap();
+///     assert_eq!(Move { direction: Direction::Right, steps: 4 }, moves[0]);
+///
+///     let mut pos = Position::new(0, 0);
+///     for m in moves {
+///         pos.change(&m.direction, m.steps);
+///     }
+///     assert_eq!(Position::new(1, -4), pos);
+///
+///     match "F 2".parse::<Move<i32>>() {
+///         Err(ParseInstructionError::Heading(e)) => assert_eq!("F", e.token),
+///         _ => panic!(),
+///     }
+/// }
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct Move<N> {
+    pub direction: Direction,
+    pub steps: N,
+}
+
+impl<N: FromStr> FromStr for Move<N> {
+    type Err = ParseInstructionError<N::Err>;
+
+    fn from_str(s: &str) -> Result<Move<N>, Self::Err> {
+        let (heading, steps) = split_instruction(s)?;
+        Ok(Move {
+            direction: Direction::try_from(heading).map_err(ParseInstructionError::Heading)?,
+            steps: parse_steps(steps)?,
+        })
+    }
+}
+
+/// A `Move` is an `Instruction` that always faces its own direction.
+impl<N> From<Move<N>> for Instruction<N> {
+    #[inline]
+    fn from(m: Move<N>) -> Instruction<N> {
+        Instruction {
+            turn_or_dir: TurnOrDir::Dir(m.direction),
+            steps: m.steps,
+        }
+    }
+}
+
+/// Splits an instruction into its heading and the (untrimmed) steps.
+fn split_instruction<E>(s: &str) -> Result<(char, &str), ParseInstructionError<E>> {
+    let s = s.trim();
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(heading) => Ok((heading, chars.as_str().trim_start())),
+        None => Err(ParseInstructionError::Heading(ParseDirectionError {
+            token: String::new(),
+        })),
+    }
+}
+
+fn parse_steps<N: FromStr>(token: &str) -> Result<N, ParseInstructionError<N::Err>> {
+    token.parse().map_err(|error| ParseInstructionError::Steps {
+        token: token.to_string(),
+        error,
+    })
+}
+
+/// The error returned when a token can not be parsed into an [Instruction](struct.Instruction.html) or a [Move](struct.Move.html).
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ParseInstructionError<E> {
+    /// The first char is not a turn, a direction or `F` (or, for a `Move`, not a direction).
+    Heading(ParseDirectionError),
+    /// The steps could not be parsed.
+    Steps { token: String, error: E },
+}
+
+impl<E: Display> Display for ParseInstructionError<E> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            ParseInstructionError::Heading(ref e) => write!(f, "{}", e),
+            ParseInstructionError::Steps {
+                ref token,
+                ref error,
+            } => write!(f, "invalid number of steps {:?}: {}", token, error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ParseInstructionError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ParseInstructionError::Heading(ref e) => Some(e),
+            ParseInstructionError::Steps { ref error, .. } => Some(error),
+        }
+    }
+}
+
+/// Describes how directions map onto the axes of a [Position](struct.Position.html).
+///
+/// Puzzles either draw their map like a screen, where `y` grows downwards, or like a graph in math class, where `y` grows upwards.
+/// Some also store positions as (row, column), which swaps the axes.
+/// Pass the coordinate system of the puzzle to [Position::step](struct.Position.html#method.step), and moving `Up` does the right thing.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::movement::CoordinateSystem;
+/// fn main() {
+///     assert_eq!(CoordinateSystem::SCREEN, CoordinateSystem::default());
+///     assert_eq!(CoordinateSystem::new(false, true), CoordinateSystem::ROW_COLUMN);
+///     assert_eq!(CoordinateSystem::MATH, CoordinateSystem::new(true, false));
+///     assert!(CoordinateSystem::SCREEN.swapped().is_swapped());
+/// }
+/// ```
+#[derive(Debug, Default, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct CoordinateSystem {
+    y_up: bool,
+    swap_axes: bool,
+}
+
+impl CoordinateSystem {
+    /// `x` grows to the right, and `y` grows downwards.
+    pub const SCREEN: CoordinateSystem = CoordinateSystem {
+        y_up: false,
+        swap_axes: false,
+    };
+
+    /// `x` grows to the right, and `y` grows upwards.
+    pub const MATH: CoordinateSystem = CoordinateSystem {
+        y_up: true,
+        swap_axes: false,
+    };
+
+    /// `x` is the row and grows downwards, `y` is the column and grows to the right.
+    pub const ROW_COLUMN: CoordinateSystem = CoordinateSystem {
+        y_up: false,
+        swap_axes: true,
+    };
+
+    /// Returns a new coordinate system.
+    /// `y_up` says whether moving up increments the vertical axis, `swap_axes` whether the vertical axis is `x` instead of `y`.
+    #[inline]
+    pub fn new(y_up: bool, swap_axes: bool) -> CoordinateSystem {
+        CoordinateSystem { y_up, swap_axes }
+    }
+
+    /// Returns the same coordinate system, with the axes swapped.
+    #[inline]
+    pub fn swapped(self) -> CoordinateSystem {
+        CoordinateSystem {
+            swap_axes: !self.swap_axes,
+            ..self
+        }
+    }
+
+    /// Returns whether moving up increments the vertical axis.
+    #[inline]
+    pub fn is_y_up(self) -> bool {
+        self.y_up
+    }
+
+    /// Returns whether the vertical axis is `x`.
+    #[inline]
+    pub fn is_swapped(self) -> bool {
+        self.swap_axes
+    }
+
+    /// Returns whether `direction` moves along `x`, and whether it moves in the positive direction.
+    #[inline]
+    fn axis(self, direction: Direction) -> (bool, bool) {
+        let (vertical, positive) = match direction {
+            Direction::Up => (true, self.y_up),
+            Direction::Down => (true, !self.y_up),
+            Direction::Right => (false, true),
+            Direction::Left => (false, false),
+        };
+        (vertical == self.swap_axes, positive)
     }
 }
 
@@ -87,7 +631,7 @@ impl Display for Direction {
 /// # Examples
 /// ```
 /// extern crate libaoc;
-/// use libaoc::movement::{Position, ManhattenDst};
+/// use libaoc::movement::Position;
 /// use libaoc::absolute::Absolute;
 /// fn main() {
 ///     let tup = (-10i32, 21i32);
@@ -191,8 +735,69 @@ where
         Position { x, y }
     }
 
+    /// Changes the position with `steps` based on the direction, in the given coordinate system.
+    /// A negative amount of steps moves the position in the reverse direction.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::movement::{CoordinateSystem, Direction, Position};
+    /// fn main() {
+    ///     let mut pos = Position::new(0, 0);
+    ///
+    ///     pos.step(&Direction::Up, 2, CoordinateSystem::MATH);
+    ///     assert_eq!(Position::new(0, 2), pos);
+    ///
+    ///     pos.step(&Direction::Up, -2, CoordinateSystem::MATH);
+    ///     assert_eq!(Position::new(0, 0), pos);
+    ///
+    ///     // (row, column) positions, where moving up decrements the row.
+    ///     pos.step(&Direction::Up, 1, CoordinateSystem::ROW_COLUMN);
+    ///     pos.step(&Direction::Right, 3, CoordinateSystem::ROW_COLUMN);
+    ///     assert_eq!(Position::new(-1, 3), pos);
+    /// }
+    /// ```
+    #[inline]
+    pub fn step(&mut self, direction: &Direction, steps: N, system: CoordinateSystem) {
+        let (on_x, positive) = system.axis(*direction);
+        let axis = if on_x { &mut self.x } else { &mut self.y };
+        if positive {
+            *axis += steps;
+        } else {
+            *axis -= steps;
+        }
+    }
+
+    /// Same as [`step`], but for a [Direction8](enum.Direction8.html).
+    /// Moving diagonally changes both axes by `steps`.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::movement::{CoordinateSystem, Direction8, Position};
+    /// fn main() {
+    ///     let mut pos = Position::new(0, 0);
+    ///     pos.step8(&Direction8::NE, 2, CoordinateSystem::MATH);
+    ///     assert_eq!(Position::new(2, 2), pos);
+    /// }
+    /// ```
+    ///
+    /// [`step`]: #method.step
+    #[inline]
+    pub fn step8(&mut self, direction: &Direction8, steps: N, system: CoordinateSystem)
+    where
+        N: Clone,
+    {
+        match direction.cardinals() {
+            (first, None) => self.step(&first, steps, system),
+            (first, Some(second)) => {
+                self.step(&first, steps.clone(), system);
+                self.step(&second, steps, system);
+            }
+        }
+    }
+
     /// Changes the position with `steps` based on the direction.
     /// If the direction is facing down, `y` is incremented, if the direction if facing up, `y` is decremented.
+    /// This is [`step`] in [`CoordinateSystem::SCREEN`].
     /// # Examples
     /// ```
     /// extern crate libaoc;
@@ -208,17 +813,15 @@ where
     /// }
     /// ```
     ///
+    /// [`step`]: #method.step
+    /// [`CoordinateSystem::SCREEN`]: struct.CoordinateSystem.html#associatedconstant.SCREEN
     #[inline]
     pub fn change(&mut self, direction: &Direction, steps: N) {
-        match *direction {
-            Direction::Up => self.y -= steps,
-            Direction::Down => self.y += steps,
-            Direction::Right => self.x += steps,
-            Direction::Left => self.x -= steps,
-        }
+        self.step(direction, steps, CoordinateSystem::SCREEN)
     }
 
     /// Same as [`change`], but now increments `y` when facing upwards, and decrements `y` when facing downwards.
+    /// This is [`step`] in [`CoordinateSystem::MATH`].
     /// # Examples
     /// ```
     /// extern crate libaoc;
@@ -235,54 +838,139 @@ where
     /// ```
     ///
     /// [`change`]: #method.change
+    /// [`step`]: #method.step
+    /// [`CoordinateSystem::MATH`]: struct.CoordinateSystem.html#associatedconstant.MATH
     #[inline]
     pub fn rev_change(&mut self, direction: &Direction, steps: N) {
-        match *direction {
-            Direction::Up => self.y += steps,
-            Direction::Down => self.y -= steps,
-            Direction::Right => self.x += steps,
-            Direction::Left => self.x -= steps,
-        }
+        self.step(direction, steps, CoordinateSystem::MATH)
     }
 
-    /// Check whether self and `other` are adjecent. That is, if the absolute x value and the absolute y value after subtracting `self` from `other`
-    /// is either (1, 0), (0, 1) or (1, 1).
+    /// Changes the position with `steps` based on the direction, following the same convention as [`change`]:
+    /// moving north decrements `y`, and moving diagonally changes both `x` and `y` by `steps`.
     /// # Examples
     /// ```
     /// extern crate libaoc;
-    /// use libaoc::movement::Position;
-    /// use libaoc::absolute::Absolute;
+    /// use libaoc::movement::{Direction8, Position};
     /// fn main() {
-    ///     let pos1 = Position::new(5, 6);
-    ///     let pos2 = Position::new(6, 7);
-    ///
-    ///
-    ///     assert_eq!(true, pos1.is_adjecent(&pos2));
-    ///     assert_eq!(true, pos2.is_adjecent(&pos1));
-    ///
-    ///     let pos3 = Position::new(-1, 0);
-    ///     let pos4 = Position::new(0, 0);
+    ///     let mut pos = Position::new(0, 0);
     ///
-    ///     assert_eq!(true, pos3.is_adjecent(&pos4));
-    ///     assert_eq!(true, pos4.is_adjecent(&pos3));
+    ///     pos.change8(&Direction8::NE, 2);
+    ///     assert_eq!(Position::new(2, -2), pos);
     ///
-    ///     assert_eq!(false, pos4.is_adjecent(&pos4));
-    ///     assert_eq!(false, pos3.is_adjecent(&pos1));
+    ///     pos.change8(&Direction8::S, 1);
+    ///     assert_eq!(Position::new(2, -1), pos);
     /// }
     /// ```
+    ///
+    /// [`change`]: #method.change
     #[inline]
-    pub fn is_adjecent(&self, other: &Position<N>) -> bool
+    pub fn change8(&mut self, direction: &Direction8, steps: N)
     where
-        N: Sub<Output = N> + Clone + From<i8> + PartialEq + Absolute,
+        N: Clone,
+    {
+        self.step8(direction, steps, CoordinateSystem::SCREEN)
+    }
+
+    /// Changes the position with `steps` based on the hexagonal direction, treating `x` and `y` as the axial coordinates `q` and `r`.
+    /// Like [`change`], moving north decrements `y`.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::movement::{HexDirection, Position};
+    /// fn main() {
+    ///     let mut pos = Position::new(0, 0);
+    ///
+    ///     pos.change_hex(&HexDirection::NE, 1);
+    ///     assert_eq!(Position::new(1, -1), pos);
+    ///
+    ///     pos.change_hex(&HexDirection::SW, 1);
+    ///     assert_eq!(Position::new(0, 0), pos);
+    /// }
+    /// ```
+    ///
+    /// [`change`]: #method.change
+    #[inline]
+    pub fn change_hex(&mut self, direction: &HexDirection, steps: N)
+    where
+        N: Clone,
     {
-        match (self - other).abs() {
-            Position { ref x, ref y } if x == &N::from(0) && y == &N::from(1) => true,
-            Position { ref x, ref y } if x == &N::from(1) && y == &N::from(0) => true,
-            Position { ref x, ref y } if x == &N::from(1) && y == &N::from(1) => true,
-            _ => false,
+        match *direction {
+            HexDirection::NE => {
+                self.x += steps.clone();
+                self.y -= steps;
+            }
+            HexDirection::E => self.x += steps,
+            HexDirection::SE => self.y += steps,
+            HexDirection::SW => {
+                self.x -= steps.clone();
+                self.y += steps;
+            }
+            HexDirection::W => self.x -= steps,
+            HexDirection::NW => self.y -= steps,
         }
     }
 
+    /// Check whether self and `other` are adjecent. That is, if the [Chebyshev](struct.Chebyshev.html) distance between them is 1,
+    /// so `other` is one of the 8 positions around `self`.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::movement::Position;
+    /// use libaoc::absolute::Absolute;
+    /// fn main() {
+    ///     let pos1 = Position::new(5, 6);
+    ///     let pos2 = Position::new(6, 7);
+    ///
+    ///
+    ///     assert_eq!(true, pos1.is_adjecent(&pos2));
+    ///     assert_eq!(true, pos2.is_adjecent(&pos1));
+    ///
+    ///     let pos3 = Position::new(-1, 0);
+    ///     let pos4 = Position::new(0, 0);
+    ///
+    ///     assert_eq!(true, pos3.is_adjecent(&pos4));
+    ///     assert_eq!(true, pos4.is_adjecent(&pos3));
+    ///
+    ///     assert_eq!(false, pos4.is_adjecent(&pos4));
+    ///     assert_eq!(false, pos3.is_adjecent(&pos1));
+    ///
+    ///     // no more underflow on unsigned positions.
+    ///     assert_eq!(true, Position::new(0u8, 0).is_adjecent(&Position::new(1, 1)));
+    /// }
+    /// ```
+    #[inline]
+    pub fn is_adjecent(&self, other: &Position<N>) -> bool
+    where
+        N: Clone + AbsDiff,
+        N::Diff: PartialOrd + Default + From<u8>,
+    {
+        self.distance_to::<Chebyshev>(other) == N::Diff::from(1)
+    }
+
+    /// Same as [`is_adjecent`], but only counts the 4 positions directly above, below, left and right of `self`.
+    /// That is, if the [Manhattan](struct.Manhattan.html) distance between them is 1.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::movement::Position;
+    /// fn main() {
+    ///     let pos = Position::new(5, 6);
+    ///
+    ///     assert_eq!(true, pos.is_adjecent4(&Position::new(5, 7)));
+    ///     assert_eq!(false, pos.is_adjecent4(&Position::new(6, 7)));
+    /// }
+    /// ```
+    ///
+    /// [`is_adjecent`]: #method.is_adjecent
+    #[inline]
+    pub fn is_adjecent4(&self, other: &Position<N>) -> bool
+    where
+        N: Clone + AbsDiff,
+        N::Diff: PartialEq + Add<Output = N::Diff> + Default + From<u8>,
+    {
+        self.distance_to::<Manhattan>(other) == N::Diff::from(1)
+    }
+
     /// Adds `steps` to y.
     /// # Examples
     /// ```
@@ -361,6 +1049,26 @@ impl<N: Absolute> Absolute for Position<N> {
             y: self.y.abs(),
         }
     }
+
+    #[inline]
+    fn checked_abs(self) -> Option<Self> {
+        Some(Position {
+            x: self.x.checked_abs()?,
+            y: self.y.checked_abs()?,
+        })
+    }
+}
+
+impl<N: AbsDiff> AbsDiff for Position<N> {
+    type Diff = Position<N::Diff>;
+
+    #[inline]
+    fn abs_diff(self, other: Self) -> Self::Diff {
+        Position {
+            x: self.x.abs_diff(other.x),
+            y: self.y.abs_diff(other.y),
+        }
+    }
 }
 
 impl<N: fmt::Display> Display for Position<N> {
@@ -376,25 +1084,354 @@ impl<N> From<(N, N)> for Position<N> {
     }
 }
 
-impl<N> Into<(N, N)> for Position<N> {
+impl<N> From<Position<N>> for (N, N) {
+    #[inline]
+    fn from(position: Position<N>) -> (N, N) {
+        (position.x, position.y)
+    }
+}
+
+/// An N-dimensional position, generic over the number of axes `D`.
+/// Use this when [Position](struct.Position.html)'s `x` and `y` are not enough, like in 3D or 4D puzzles.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::movement::{DistanceTo, Manhattan, Position3, Position4, PositionN};
+/// use libaoc::absolute::Absolute;
+/// fn main() {
+///     let p1 = Position3::new([1, -2, 3]);
+///     let p2 = Position3::from((4, 5, -6));
+///
+///     assert_eq!(Position3::new([5, 3, -3]), p1 + p2);
+///     assert_eq!(Position3::new([3, 7, -9]), p2 - &p1);
+///     assert_eq!(Position3::new([3, 7, 9]), (p2 - p1).abs());
+///     assert_eq!(19u32, p1.distance_to::<Manhattan>(&p2));
+///
+///     let p4: Position4<i64> = [1, 2, 3, 4].into();
+///     assert_eq!("(1, 2, 3, 4)", p4.to_string());
+///     assert_eq!(3, p4[2]);
+///
+///     let tuple: (i64, i64, i64, i64) = p4.into();
+///     assert_eq!((1, 2, 3, 4), tuple);
+///
+///     let p2d = PositionN::from((10u8, 20u8));
+///     assert_eq!(&[10, 20], p2d.get_ref());
+/// }
+/// ```
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct PositionN<N, const D: usize> {
+    coords: [N; D],
+}
+
+/// A position with 3 axes.
+pub type Position3<N> = PositionN<N, 3>;
+
+/// A position with 4 axes.
+pub type Position4<N> = PositionN<N, 4>;
+
+/// Combines two arrays of coordinates pairwise with `f`.
+#[inline]
+fn zip_coords<N, M, F, const D: usize>(lhs: [N; D], rhs: [N; D], mut f: F) -> [M; D]
+where
+    F: FnMut(N, N) -> M,
+{
+    let mut rhs = IntoIterator::into_iter(rhs);
+    lhs.map(|l| match rhs.next() {
+        Some(r) => f(l, r),
+        None => unreachable!(),
+    })
+}
+
+macro_rules! binops_n {
+    (impl $imp:ident, $method:ident for $pos:ident, $oper:tt) => {
+
+        // impl Imp<pos<N, D>> for pos<N, D>. Does Not require Clone, because the value is owned.
+        impl<N, const D: usize> $imp<$pos<N, D>> for $pos<N, D>
+        where
+            N: $imp<Output = N>
+        {
+            type Output = $pos<N, D>;
+
+            #[inline]
+            fn $method(self, other: $pos<N, D>) -> Self::Output {
+                $pos { coords: zip_coords(self.coords, other.coords, |l, r| l $oper r) }
+            }
+        }
+
+        // impl <'a> Imp<pos<N, D>> for 'a pos<N, D>
+        impl<'a, N, const D: usize> $imp<$pos<N, D>> for &'a $pos<N, D>
+        where
+            N: $imp<Output = N> + Clone
+        {
+            type Output = $pos<N, D>;
+
+            #[inline]
+            fn $method(self, other: $pos<N, D>) -> Self::Output {
+                $pos { coords: zip_coords(self.coords.clone(), other.coords, |l, r| l $oper r) }
+            }
+        }
+
+        // impl <'b> Imp<&'b pos<N, D>> for pos<N, D>
+        impl<'b, N, const D: usize> $imp<&'b $pos<N, D>> for $pos<N, D>
+        where
+            N: $imp<Output = N> + Clone
+        {
+            type Output = $pos<N, D>;
+
+            #[inline]
+            fn $method(self, other: &'b $pos<N, D>) -> Self::Output {
+                $pos { coords: zip_coords(self.coords, other.coords.clone(), |l, r| l $oper r) }
+            }
+        }
+
+        // impl <'a, 'b> Imp<'b pos<N, D>> for 'a pos<N, D>
+        impl<'a, 'b, N, const D: usize> $imp<&'b $pos<N, D>> for &'a $pos<N, D>
+        where
+            N: $imp<Output = N> + Clone
+        {
+            type Output = $pos<N, D>;
+
+            #[inline]
+            fn $method(self, other: &'b $pos<N, D>) -> Self::Output {
+                $pos { coords: zip_coords(self.coords.clone(), other.coords.clone(), |l, r| l $oper r) }
+            }
+        }
+    }
+}
+
+binops_n!(impl Add, add for PositionN, +);
+binops_n!(impl Sub, sub for PositionN, -);
+
+impl<N, const D: usize> PositionN<N, D> {
+    /// Returns a new PositionN.
     #[inline]
-    fn into(self) -> (N, N) {
-        (self.x, self.y)
+    pub fn new(coords: [N; D]) -> PositionN<N, D> {
+        PositionN { coords }
+    }
+
+    /// Returns a reference to the coordinates.
+    #[inline]
+    pub fn get_ref(&self) -> &[N; D] {
+        &self.coords
+    }
+
+    /// Returns the coordinates, consuming the position.
+    #[inline]
+    pub fn into_inner(self) -> [N; D] {
+        self.coords
+    }
+
+    /// Clones the coordinates into an array.
+    #[inline]
+    pub fn to_array(&self) -> [N; D]
+    where
+        N: Clone,
+    {
+        self.coords.clone()
+    }
+}
+
+impl<N> PositionN<N, 3>
+where
+    N: AddAssign<N> + SubAssign<N> + Clone,
+{
+    /// Changes the position with `steps` based on the hexagonal direction, treating the axes as the cube coordinates `q`, `r` and `s`.
+    /// Every step keeps `q + r + s` unchanged.
+    /// # Examples
+    /// ```
+    /// extern crate libaoc;
+    /// use libaoc::movement::{DistanceTo, HexDirection, Manhattan, Position3};
+    /// fn main() {
+    ///     let mut pos = Position3::new([0, 0, 0]);
+    ///
+    ///     pos.change_hex(&HexDirection::NE, 2);
+    ///     pos.change_hex(&HexDirection::SE, 1);
+    ///     assert_eq!(Position3::new([2, -1, -1]), pos);
+    ///
+    ///     // the hex distance is half of the manhatten distance in cube coordinates.
+    ///     let distance: u32 = pos.distance_to::<Manhattan>(&Position3::new([0, 0, 0]));
+    ///     assert_eq!(2, distance / 2);
+    /// }
+    /// ```
+    #[inline]
+    pub fn change_hex(&mut self, direction: &HexDirection, steps: N) {
+        let [ref mut q, ref mut r, ref mut s] = self.coords;
+        match *direction {
+            HexDirection::NE => {
+                *q += steps.clone();
+                *r -= steps;
+            }
+            HexDirection::E => {
+                *q += steps.clone();
+                *s -= steps;
+            }
+            HexDirection::SE => {
+                *r += steps.clone();
+                *s -= steps;
+            }
+            HexDirection::SW => {
+                *q -= steps.clone();
+                *r += steps;
+            }
+            HexDirection::W => {
+                *q -= steps.clone();
+                *s += steps;
+            }
+            HexDirection::NW => {
+                *r -= steps.clone();
+                *s += steps;
+            }
+        }
+    }
+}
+
+impl<N, const D: usize> Index<usize> for PositionN<N, D> {
+    type Output = N;
+
+    #[inline]
+    fn index(&self, axis: usize) -> &N {
+        &self.coords[axis]
+    }
+}
+
+impl<N, const D: usize> IndexMut<usize> for PositionN<N, D> {
+    #[inline]
+    fn index_mut(&mut self, axis: usize) -> &mut N {
+        &mut self.coords[axis]
+    }
+}
+
+impl<N: Absolute, const D: usize> Absolute for PositionN<N, D> {
+    #[inline]
+    fn abs(self) -> Self {
+        PositionN {
+            coords: self.coords.map(N::abs),
+        }
+    }
+
+    #[inline]
+    fn checked_abs(self) -> Option<Self> {
+        let checked = self.coords.map(N::checked_abs);
+        if checked.iter().any(Option::is_none) {
+            return None;
+        }
+        Some(PositionN {
+            coords: checked.map(Option::unwrap),
+        })
+    }
+}
+
+impl<N: AbsDiff, const D: usize> AbsDiff for PositionN<N, D> {
+    type Diff = PositionN<N::Diff, D>;
+
+    #[inline]
+    fn abs_diff(self, other: Self) -> Self::Diff {
+        PositionN {
+            coords: zip_coords(self.coords, other.coords, N::abs_diff),
+        }
+    }
+}
+
+impl<N: fmt::Display, const D: usize> Display for PositionN<N, D> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "(")?;
+        for (idx, n) in self.coords.iter().enumerate() {
+            if idx != 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", n)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl<N, const D: usize> From<[N; D]> for PositionN<N, D> {
+    #[inline]
+    fn from(coords: [N; D]) -> PositionN<N, D> {
+        PositionN { coords }
+    }
+}
+
+impl<N, const D: usize> From<PositionN<N, D>> for [N; D] {
+    #[inline]
+    fn from(position: PositionN<N, D>) -> [N; D] {
+        position.coords
+    }
+}
+
+impl<N> From<Position<N>> for PositionN<N, 2> {
+    #[inline]
+    fn from(position: Position<N>) -> PositionN<N, 2> {
+        PositionN {
+            coords: [position.x, position.y],
+        }
+    }
+}
+
+impl<N> From<(N, N)> for PositionN<N, 2> {
+    #[inline]
+    fn from((n1, n2): (N, N)) -> PositionN<N, 2> {
+        PositionN { coords: [n1, n2] }
+    }
+}
+
+impl<N> From<PositionN<N, 2>> for (N, N) {
+    #[inline]
+    fn from(position: PositionN<N, 2>) -> (N, N) {
+        let [n1, n2] = position.coords;
+        (n1, n2)
+    }
+}
+
+impl<N> From<(N, N, N)> for PositionN<N, 3> {
+    #[inline]
+    fn from((n1, n2, n3): (N, N, N)) -> PositionN<N, 3> {
+        PositionN {
+            coords: [n1, n2, n3],
+        }
+    }
+}
+
+impl<N> From<PositionN<N, 3>> for (N, N, N) {
+    #[inline]
+    fn from(position: PositionN<N, 3>) -> (N, N, N) {
+        let [n1, n2, n3] = position.coords;
+        (n1, n2, n3)
+    }
+}
+
+impl<N> From<(N, N, N, N)> for PositionN<N, 4> {
+    #[inline]
+    fn from((n1, n2, n3, n4): (N, N, N, N)) -> PositionN<N, 4> {
+        PositionN {
+            coords: [n1, n2, n3, n4],
+        }
+    }
+}
+
+impl<N> From<PositionN<N, 4>> for (N, N, N, N) {
+    #[inline]
+    fn from(position: PositionN<N, 4>) -> (N, N, N, N) {
+        let [n1, n2, n3, n4] = position.coords;
+        (n1, n2, n3, n4)
     }
 }
 
 /// Returns the manhatten distance of any Position with type N.
-/// A position is either a tuple, or the struct [Position](struct.Position.html).
+/// A position is either a tuple, the struct [Position](struct.Position.html), or the struct [PositionN](struct.PositionN.html).
 /// the manhatten distance is the sum of the absolute values of a coordinate.
 ///
+/// To measure between two positions, use the [Manhattan](struct.Manhattan.html) metric.
+///
 /// # Examples
 /// ```
 /// extern crate libaoc;
-/// use libaoc::movement::{ManhattenDst, Position};
+/// use libaoc::movement::{DistanceTo, Manhattan, ManhattenDst, Position};
 ///
 /// fn main() {
 ///     let pos = Position::new(-1, 11i16);
 ///     assert_eq!(12, pos.manhattendst());
+///     assert_eq!(12, pos.distance_to::<Manhattan>(&Position::new(0, 0)));
 /// }
 /// ```
 pub trait ManhattenDst<N>
@@ -434,3 +1471,180 @@ where
         self.0.abs() + self.1.abs() + self.2.abs()
     }
 }
+
+impl<N, const D: usize> ManhattenDst<N> for PositionN<N, D>
+where
+    N: Add<Output = N> + Absolute + Default,
+{
+    #[inline]
+    fn manhattendst(self) -> N {
+        IntoIterator::into_iter(self.coords).fold(N::default(), |acc, n| acc + n.abs())
+    }
+}
+
+/// Positions that can be compared axis by axis, so every [Metric](trait.Metric.html) can measure the distance between them.
+/// Implemented for [Position](struct.Position.html), [PositionN](struct.PositionN.html), and tuples of 2 or 3 items.
+pub trait AxisDiffs {
+    /// The type of the difference along a single axis.
+    type Diff;
+
+    /// The differences along every axis.
+    type Diffs: IntoIterator<Item = Self::Diff>;
+
+    /// Returns the absolute difference between `self` and `other` along every axis.
+    fn axis_diffs(&self, other: &Self) -> Self::Diffs;
+}
+
+impl<N> AxisDiffs for Position<N>
+where
+    N: Clone + AbsDiff,
+{
+    type Diff = N::Diff;
+    type Diffs = [N::Diff; 2];
+
+    #[inline]
+    fn axis_diffs(&self, other: &Self) -> Self::Diffs {
+        let diff = self.clone().abs_diff(other.clone());
+        [diff.x, diff.y]
+    }
+}
+
+impl<N, const D: usize> AxisDiffs for PositionN<N, D>
+where
+    N: Clone + AbsDiff,
+{
+    type Diff = N::Diff;
+    type Diffs = [N::Diff; D];
+
+    #[inline]
+    fn axis_diffs(&self, other: &Self) -> Self::Diffs {
+        self.clone().abs_diff(other.clone()).coords
+    }
+}
+
+impl<N> AxisDiffs for (N, N)
+where
+    N: Clone + AbsDiff,
+{
+    type Diff = N::Diff;
+    type Diffs = [N::Diff; 2];
+
+    #[inline]
+    fn axis_diffs(&self, other: &Self) -> Self::Diffs {
+        let (x, y) = self.clone().abs_diff(other.clone());
+        [x, y]
+    }
+}
+
+impl<N> AxisDiffs for (N, N, N)
+where
+    N: Clone + AbsDiff,
+{
+    type Diff = N::Diff;
+    type Diffs = [N::Diff; 3];
+
+    #[inline]
+    fn axis_diffs(&self, other: &Self) -> Self::Diffs {
+        let (x, y, z) = self.clone().abs_diff(other.clone());
+        [x, y, z]
+    }
+}
+
+/// A way to measure the distance between two positions of type `P`.
+/// Use it through [DistanceTo](trait.DistanceTo.html).
+pub trait Metric<P> {
+    /// The type of the distance.
+    type Output;
+
+    /// Returns the distance between `a` and `b`.
+    fn distance(a: &P, b: &P) -> Self::Output;
+}
+
+/// The sum of the differences along every axis. The amount of steps needed when only moving along one axis at a time.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct Manhattan;
+
+/// The largest difference along any axis. The amount of steps needed when diagonal moves are allowed.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct Chebyshev;
+
+/// The sum of the squared differences along every axis.
+/// This is the square of the straight-line distance, which is enough to compare distances without taking a square root.
+#[derive(Debug, Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Hash)]
+pub struct SquaredEuclidean;
+
+impl<P> Metric<P> for Manhattan
+where
+    P: AxisDiffs,
+    P::Diff: Add<Output = P::Diff> + Default,
+{
+    type Output = P::Diff;
+
+    #[inline]
+    fn distance(a: &P, b: &P) -> Self::Output {
+        a.axis_diffs(b)
+            .into_iter()
+            .fold(P::Diff::default(), |acc, d| acc + d)
+    }
+}
+
+impl<P> Metric<P> for Chebyshev
+where
+    P: AxisDiffs,
+    P::Diff: PartialOrd + Default,
+{
+    type Output = P::Diff;
+
+    #[inline]
+    fn distance(a: &P, b: &P) -> Self::Output {
+        a.axis_diffs(b)
+            .into_iter()
+            .fold(P::Diff::default(), |acc, d| if d > acc { d } else { acc })
+    }
+}
+
+impl<P> Metric<P> for SquaredEuclidean
+where
+    P: AxisDiffs,
+    P::Diff: Add<Output = P::Diff> + Mul<Output = P::Diff> + Clone + Default,
+{
+    type Output = P::Diff;
+
+    #[inline]
+    fn distance(a: &P, b: &P) -> Self::Output {
+        a.axis_diffs(b)
+            .into_iter()
+            .fold(P::Diff::default(), |acc, d| acc + d.clone() * d)
+    }
+}
+
+/// Measures the distance between two positions, using any [Metric](trait.Metric.html).
+/// Implemented for every type that implements [AxisDiffs](trait.AxisDiffs.html).
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::movement::{Chebyshev, DistanceTo, Manhattan, Position, Position3, SquaredEuclidean};
+/// fn main() {
+///     let a = Position::new(1i64, -2);
+///     let b = Position::new(4, 2);
+///
+///     // the distance between two signed positions is unsigned.
+///     assert_eq!(7u64, a.distance_to::<Manhattan>(&b));
+///     assert_eq!(4u64, a.distance_to::<Chebyshev>(&b));
+///     assert_eq!(25u64, a.distance_to::<SquaredEuclidean>(&b));
+///
+///     assert_eq!(6u32, (0, 0, 0).distance_to::<Manhattan>(&(1, -2, 3)));
+///     assert_eq!(3u32, Position3::new([0, 0, 0]).distance_to::<Chebyshev>(&Position3::new([1, -2, 3])));
+///
+///     assert_eq!(5.0, (0.0, 0.0).distance_to::<SquaredEuclidean>(&(1.0, 2.0)));
+/// }
+/// ```
+pub trait DistanceTo: Sized {
+    /// Returns the distance between `self` and `other`, measured with `M`.
+    #[inline]
+    fn distance_to<M: Metric<Self>>(&self, other: &Self) -> M::Output {
+        M::distance(self, other)
+    }
+}
+
+impl<P: AxisDiffs> DistanceTo for P {}
@@ -0,0 +1,627 @@
+//! Parsing of the lines of a puzzle input, without splitting them by hand.
+//!
+//! [scan!](../macro.scan.html) matches a line against a template like `"#{} @ {},{}: {}x{}"`,
+//! and converts every placeholder to its own type.
+//! [ParseInts](trait.ParseInts.html) takes every integer from a line, whatever is around them,
+//! and [ParseGroups](trait.ParseGroups.html) splits an input into the groups of lines that are separated by blank lines.
+//! [parse_struct!](../macro.parse_struct.html) implements `FromStr` for a struct whose fields are separated by a delimiter.
+use convert::{ConvertError, TryConvert};
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+/// Matches `input` against `template`, and returns the text of every `{}` placeholder in the template.
+///
+/// Everything in the template but the placeholders must match the input literally.
+/// A placeholder captures everything up to the first occurence of the text that follows it in the template.
+/// The last placeholder captures up to the text that ends the template, which must end the input as well,
+/// or the rest of the input if the template ends with the placeholder.
+/// If that text does not occur, the error points at the start of the placeholder.
+/// # Panics
+/// Panics if two placeholders follow each other directly, since there is no text to tell where the first one ends.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::parse::parse_pattern;
+/// fn main() {
+///     let captures = parse_pattern("#{} @ {},{}: {}x{}", "#1 @ 3,2: 5x4").unwrap();
+///     assert_eq!(vec!["1", "3", "2", "5", "4"], captures);
+///
+///     let error = parse_pattern("#{} @ {},{}: {}x{}", "1 @ 3,2: 5x4").unwrap_err();
+///     assert_eq!(0, error.column);
+///
+///     // there is no ',' after the second placeholder.
+///     let error = parse_pattern("#{} @ {},{}: {}x{}", "#1 @ 3;2: 5x4").unwrap_err();
+///     assert_eq!(5, error.column);
+///
+///     // the text at the end of the template is matched at the end of the input.
+///     assert_eq!(vec!["1.5"], parse_pattern("{}.", "1.5.").unwrap());
+/// }
+/// ```
+#[inline]
+pub fn parse_pattern<'a>(template: &str, input: &'a str) -> Result<Vec<&'a str>, ScanError> {
+    Ok(match_pattern(template, input)?
+        .into_iter()
+        .map(|(_, capture)| capture)
+        .collect())
+}
+
+/// Same as [parse_pattern](fn.parse_pattern.html), but also returns the column every capture starts at.
+/// This is used by [scan!](../macro.scan.html).
+#[doc(hidden)]
+pub fn match_pattern<'a>(
+    template: &str,
+    input: &'a str,
+) -> Result<Vec<(usize, &'a str)>, ScanError> {
+    let literals: Vec<&str> = template.split("{}").collect();
+    let last = literals.len() - 1;
+    let mut captures = Vec::with_capacity(last);
+    let mut column = 0;
+
+    for (idx, literal) in literals.iter().enumerate() {
+        assert!(
+            idx == 0 || idx == last || !literal.is_empty(),
+            "the placeholders of {:?} follow each other directly",
+            template
+        );
+
+        if idx > 0 {
+            // the capture of the previous placeholder ends where this literal starts.
+            let end = if idx == last {
+                // the last literal must end the input, so it is matched as a suffix.
+                match input.len().checked_sub(literal.len()) {
+                    Some(end) if end >= column && input.ends_with(literal) => end,
+                    _ => return Err(ScanError::literal(column, literal)),
+                }
+            } else {
+                match input[column..].find(literal) {
+                    Some(len) => column + len,
+                    None => return Err(ScanError::literal(column, literal)),
+                }
+            };
+            captures.push((column, &input[column..end]));
+            column = end;
+        }
+
+        if !input[column..].starts_with(literal) {
+            return Err(ScanError::literal(column, literal));
+        }
+        column += literal.len();
+    }
+
+    if column == input.len() {
+        Ok(captures)
+    } else {
+        Err(ScanError {
+            column,
+            kind: ScanErrorKind::Trailing,
+        })
+    }
+}
+
+/// Parses a capture that starts at `column` into `T`, reporting that column on an error.
+/// This is used by [scan!](../macro.scan.html).
+#[doc(hidden)]
+#[inline]
+pub fn parse_capture<T>(column: usize, capture: &str) -> Result<T, ScanError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    capture.parse().map_err(|error| ScanError {
+        column,
+        kind: ScanErrorKind::Parse {
+            text: capture.to_string(),
+            error: Box::new(error),
+        },
+    })
+}
+
+/// Matches a line against a template with `{}` placeholders, and converts the placeholders into a tuple of the given types.
+/// Returns a `Result` with a [ScanError](parse/struct.ScanError.html) on failure.
+///
+/// See [parse_pattern](parse/fn.parse_pattern.html) for how the template is matched.
+/// # Panics
+/// Panics if the template does not have exactly one placeholder per type.
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate libaoc;
+/// use libaoc::parse::ScanErrorKind;
+/// fn main() {
+///     let (id, x, y, w, h) = scan!("#1 @ 3,2: 5x4", "#{} @ {},{}: {}x{}" => u32, i64, i64, usize, usize).unwrap();
+///     assert_eq!((1, 3, 2, 5, 4), (id, x, y, w, h));
+///
+///     let (name, weight) = scan!("pbga (66)", "{} ({})" => String, u32).unwrap();
+///     assert_eq!(("pbga", 66), (&*name, weight));
+///
+///     let error = scan!("#1 @ 3,two: 5x4", "#{} @ {},{}: {}x{}" => u32, i64, i64, usize, usize).unwrap_err();
+///     assert_eq!(7, error.column);
+///     match error.kind {
+///         ScanErrorKind::Parse { text, .. } => assert_eq!("two", text),
+///         _ => panic!(),
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! scan {
+    ($input:expr, $template:expr => $($ty:ty),+ $(,)*) => {{
+        let input: &str = &$input;
+        let template: &str = $template;
+        let types = [$(stringify!($ty)),+].len();
+
+        $crate::parse::match_pattern(template, input).and_then(|captures| {
+            assert_eq!(
+                types,
+                captures.len(),
+                "{:?} does not have one placeholder per type",
+                template
+            );
+            let mut captures = captures.into_iter();
+            Ok(($({
+                let (column, capture) = captures.next().unwrap();
+                $crate::parse::parse_capture::<$ty>(column, capture)?
+            },)+))
+        })
+    }};
+}
+
+/// The error returned when a line does not match a template, or a placeholder could not be converted.
+#[derive(Debug)]
+pub struct ScanError {
+    /// Where in the line the template stopped matching, counted in bytes from 0.
+    pub column: usize,
+    /// Why the template stopped matching.
+    pub kind: ScanErrorKind,
+}
+
+/// The kind of a [ScanError](struct.ScanError.html).
+#[derive(Debug)]
+pub enum ScanErrorKind {
+    /// The line did not contain the `expected` text of the template.
+    Literal { expected: String },
+    /// The template was matched, but the line continued.
+    Trailing,
+    /// The `text` of a placeholder could not be converted.
+    Parse {
+        text: String,
+        error: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl ScanError {
+    #[inline]
+    fn literal(column: usize, expected: &str) -> ScanError {
+        ScanError {
+            column,
+            kind: ScanErrorKind::Literal {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ScanErrorKind::Literal { ref expected } => {
+                write!(f, "expected {:?} at column {}", expected, self.column)
+            }
+            ScanErrorKind::Trailing => write!(f, "unexpected text at column {}", self.column),
+            ScanErrorKind::Parse {
+                ref text,
+                ref error,
+            } => write!(
+                f,
+                "could not convert {:?} at column {}: {}",
+                text, self.column, error
+            ),
+        }
+    }
+}
+
+impl Error for ScanError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.kind {
+            ScanErrorKind::Parse { ref error, .. } => Some(&**error),
+            ScanErrorKind::Literal { .. } | ScanErrorKind::Trailing => None,
+        }
+    }
+}
+
+/// Extracts all integers from a str, ignoring everything around them.
+///
+/// A `-` or `+` directly before a number is its sign, unless the sign follows a letter or digit itself.
+/// So `"x=-3"` holds -3, but `"2-4"` holds 2 and 4.
+///
+/// Every integer is parsed with the sign it is written with, so a negative number is an error for an unsigned `T`,
+/// just like a number that does not fit in `T`.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::convert::ConvertError;
+/// use libaoc::parse::ParseInts;
+/// fn main() {
+///     let line = "target area: x=20..30, y=-10..-5";
+///     assert_eq!(Ok(vec![20, 30, -10, -5]), line.ints::<i32>().collect());
+///
+///     assert_eq!(Ok(vec![2, 4, 6, 8]), "2-4,6-8".ints::<u8>().collect());
+///     assert!("x=-3".ints::<u32>().next().unwrap().is_err());
+///
+///     let [px, py, vx, vy] = "p=0,4 v=+3,-3".ints_array::<i64, 4>().unwrap();
+///     assert_eq!((0, 4, 3, -3), (px, py, vx, vy));
+///
+///     // an array converts into a tuple.
+///     let (from, to): (usize, usize) = "move from 1 to 3".ints_array::<usize, 2>().unwrap().into();
+///     assert_eq!((1, 3), (from, to));
+///
+///     match "1 300 2".ints_array::<u8, 3>() {
+///         Err(ConvertError::Parse { index, .. }) => assert_eq!(1, index),
+///         _ => panic!(),
+///     }
+/// }
+/// ```
+pub trait ParseInts {
+    /// Returns a lazy iterator over the integers in self.
+    fn ints<T: FromStr>(&self) -> Ints<'_, T>;
+
+    /// Returns the integers in self as an array, if there are exactly N of them and all of them can be parsed.
+    /// Fails the same way as [try_convert_array](../convert/trait.TryConvert.html#tymethod.try_convert_array).
+    fn ints_array<T: FromStr, const N: usize>(&self) -> Result<[T; N], ConvertError<T::Err>>;
+}
+
+impl ParseInts for str {
+    #[inline]
+    fn ints<T: FromStr>(&self) -> Ints<'_, T> {
+        Ints {
+            tokens: IntTokens { s: self, pos: 0 },
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn ints_array<T: FromStr, const N: usize>(&self) -> Result<[T; N], ConvertError<T::Err>> {
+        IntTokens { s: self, pos: 0 }.try_convert_array()
+    }
+}
+
+/// The Iterator that gets returned from [ints()](trait.ParseInts.html#tymethod.ints)
+#[derive(Debug, Clone)]
+pub struct Ints<'a, T> {
+    tokens: IntTokens<'a>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: FromStr> Iterator for Ints<'a, T> {
+    type Item = Result<T, T::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tokens.next().map(str::parse)
+    }
+}
+
+/// The text of every integer in a str, including its sign.
+#[derive(Debug, Clone)]
+struct IntTokens<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Iterator for IntTokens<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let bytes = self.s.as_bytes();
+        let digit = self.pos + bytes[self.pos..].iter().position(u8::is_ascii_digit)?;
+
+        let mut start = digit;
+        if digit > self.pos && (bytes[digit - 1] == b'-' || bytes[digit - 1] == b'+') {
+            let after_alphanumeric = digit > 1 && bytes[digit - 2].is_ascii_alphanumeric();
+            if !after_alphanumeric {
+                start -= 1;
+            }
+        }
+        let end = digit
+            + bytes[digit..]
+                .iter()
+                .position(|b| !b.is_ascii_digit())
+                .unwrap_or(bytes.len() - digit);
+        self.pos = end;
+
+        Some(&self.s[start..end])
+    }
+}
+
+/// Splits a str into groups of lines, that are separated by one or more blank lines.
+///
+/// Both `\n` and `\r\n` line endings are understood, and a line with only whitespace counts as blank.
+/// The groups do not include the line ending of their last line.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::parse::ParseGroups;
+/// fn main() {
+///     let input = "1000\n2000\n\n4000\n\n\n5000\n6000\n";
+///     assert_eq!(vec!["1000\n2000", "4000", "5000\n6000"], input.groups().collect::<Vec<_>>());
+///
+///     let input = "a\r\nb\r\n\r\nc\r\n";
+///     assert_eq!(vec!["a\r\nb", "c"], input.groups().collect::<Vec<_>>());
+///
+///     let sums = "1\n2\n\n3".parse_groups(|group| {
+///         group.lines().map(|line| line.parse::<u32>()).sum::<Result<u32, _>>()
+///     });
+///     assert_eq!(vec![3, 3], sums.unwrap());
+///
+///     let error = "1\n\n2\n\nx".parse_groups(|group| group.parse::<u32>()).unwrap_err();
+///     assert_eq!(2, error.index);
+/// }
+/// ```
+pub trait ParseGroups {
+    /// Returns a lazy iterator over the groups in self.
+    fn groups(&self) -> Groups<'_>;
+
+    /// Parses every group with `parse`. On the first error, returns the error together with the index of the group, counted from 0.
+    fn parse_groups<U, E, F>(&self, parse: F) -> Result<Vec<U>, GroupError<E>>
+    where
+        F: FnMut(&str) -> Result<U, E>;
+}
+
+impl ParseGroups for str {
+    #[inline]
+    fn groups(&self) -> Groups<'_> {
+        Groups { s: self, pos: 0 }
+    }
+
+    fn parse_groups<U, E, F>(&self, mut parse: F) -> Result<Vec<U>, GroupError<E>>
+    where
+        F: FnMut(&str) -> Result<U, E>,
+    {
+        self.groups()
+            .enumerate()
+            .map(|(index, group)| parse(group).map_err(|error| GroupError { index, error }))
+            .collect()
+    }
+}
+
+/// The Iterator that gets returned from [groups()](trait.ParseGroups.html#tymethod.groups)
+#[derive(Debug, Clone)]
+pub struct Groups<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl<'a> Groups<'a> {
+    /// Returns the line that starts at `pos`, without its line ending, and where the next line starts.
+    #[inline]
+    fn line_at(&self, pos: usize) -> (&'a str, usize) {
+        let rest = &self.s[pos..];
+        let (line, next) = match rest.find('\n') {
+            Some(idx) => (&rest[..idx], pos + idx + 1),
+            None => (rest, self.s.len()),
+        };
+        (line.trim_end_matches('\r'), next)
+    }
+}
+
+impl<'a> Iterator for Groups<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        // skip the blank lines before the group.
+        let start = loop {
+            if self.pos == self.s.len() {
+                return None;
+            }
+            let (line, next) = self.line_at(self.pos);
+            if !line.trim().is_empty() {
+                break self.pos;
+            }
+            self.pos = next;
+        };
+
+        let mut end = start;
+        while self.pos < self.s.len() {
+            let (line, next) = self.line_at(self.pos);
+            if line.trim().is_empty() {
+                break;
+            }
+            end = self.pos + line.len();
+            self.pos = next;
+        }
+        Some(&self.s[start..end])
+    }
+}
+
+/// The error returned by [parse_groups()](trait.ParseGroups.html#tymethod.parse_groups).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupError<E> {
+    /// The index of the group that could not be parsed, counted from 0.
+    pub index: usize,
+    /// Why the group could not be parsed.
+    pub error: E,
+}
+
+impl<E: fmt::Display> fmt::Display for GroupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "could not parse group {}: {}", self.index, self.error)
+    }
+}
+
+impl<E: Error + 'static> Error for GroupError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Splits `s` into the fields of a struct.
+/// Repeated delimiters are collapsed if the delimiter is whitespace, so the fields can be aligned.
+/// This is used by [parse_struct!](../macro.parse_struct.html).
+#[doc(hidden)]
+#[inline]
+pub fn split_fields<'a>(s: &'a str, delimiter: &'a str) -> impl Iterator<Item = &'a str> + 'a {
+    let collapse = delimiter.chars().all(char::is_whitespace);
+    s.trim()
+        .split(delimiter)
+        .filter(move |text| !(collapse && text.is_empty()))
+}
+
+/// Parses the `field` of `struct_name` from `text`, which is `None` if the input ran out of fields.
+/// An empty field counts as missing too.
+/// This is used by [parse_struct!](../macro.parse_struct.html).
+#[inline]
+pub fn parse_field<T>(
+    struct_name: &'static str,
+    field: &'static str,
+    text: Option<&str>,
+) -> Result<T, ParseStructError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    let text = match text.map(str::trim) {
+        Some(text) if !text.is_empty() => text,
+        _ => {
+            return Err(ParseStructError {
+                struct_name,
+                field: Some(field),
+                kind: ParseStructErrorKind::Missing,
+            })
+        }
+    };
+
+    text.parse().map_err(|error: T::Err| ParseStructError {
+        struct_name,
+        field: Some(field),
+        kind: ParseStructErrorKind::Invalid {
+            text: text.to_string(),
+            error: Box::new(error),
+        },
+    })
+}
+
+/// Implements `FromStr` for a struct, whose fields are separated by a delimiter.
+/// The fields are listed in the order they appear in the input, and every field is parsed with its own `FromStr` implementation.
+/// Whitespace around the input and around every field is ignored, and a whitespace delimiter may be repeated.
+/// A field that is empty is missing.
+///
+/// The `Err` of the implementation is a [ParseStructError](parse/struct.ParseStructError.html), naming the field that failed.
+/// # Examples
+/// ```
+/// #[macro_use]
+/// extern crate libaoc;
+/// use libaoc::parse::{ParseStructError, ParseStructErrorKind};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Reindeer {
+///     name: String,
+///     speed: u32,
+///     fly: u32,
+///     rest: u32,
+/// }
+///
+/// parse_struct!(Reindeer, "," => name, speed, fly, rest);
+///
+/// fn main() {
+///     let comet: Reindeer = "Comet, 14, 10, 127".parse().unwrap();
+///     assert_eq!(Reindeer { name: String::from("Comet"), speed: 14, fly: 10, rest: 127 }, comet);
+///
+///     let error = "Dancer, 16, fast, 162".parse::<Reindeer>().unwrap_err();
+///     assert_eq!(Some("fly"), error.field);
+///     assert_eq!(
+///         "could not parse field `fly` of `Reindeer` from \"fast\": invalid digit found in string",
+///         error.to_string()
+///     );
+///
+///     let error = "Vixen, 8".parse::<Reindeer>().unwrap_err();
+///     assert!(matches!(error.kind, ParseStructErrorKind::Missing));
+///     assert_eq!(Some("fly"), error.field);
+/// }
+/// ```
+#[macro_export]
+macro_rules! parse_struct {
+    ($name:ident, $delimiter:expr => $($field:ident),+ $(,)*) => {
+        impl ::std::str::FromStr for $name {
+            type Err = $crate::parse::ParseStructError;
+
+            fn from_str(s: &str) -> Result<$name, $crate::parse::ParseStructError> {
+                let delimiter = $delimiter.to_string();
+                let mut parts = $crate::parse::split_fields(s, &delimiter);
+                // the fields of a struct expression are evaluated in the order they are written.
+                let parsed = $name {
+                    $($field: $crate::parse::parse_field(stringify!($name), stringify!($field), parts.next())?,)+
+                };
+
+                match parts.next() {
+                    Some(rest) => Err($crate::parse::ParseStructError {
+                        struct_name: stringify!($name),
+                        field: None,
+                        kind: $crate::parse::ParseStructErrorKind::TooMany {
+                            text: rest.to_string(),
+                        },
+                    }),
+                    None => Ok(parsed),
+                }
+            }
+        }
+    };
+}
+
+/// The error returned by the `FromStr` implementations of [parse_struct!](../macro.parse_struct.html).
+#[derive(Debug)]
+pub struct ParseStructError {
+    /// The name of the struct that was parsed.
+    pub struct_name: &'static str,
+    /// The field that could not be parsed, or `None` if there were too many fields.
+    pub field: Option<&'static str>,
+    /// Why parsing failed.
+    pub kind: ParseStructErrorKind,
+}
+
+/// The kind of a [ParseStructError](struct.ParseStructError.html).
+#[derive(Debug)]
+pub enum ParseStructErrorKind {
+    /// The input ran out before the field, or the field was empty.
+    Missing,
+    /// The `text` of the field could not be parsed, `error` is the error of its `FromStr` implementation.
+    Invalid {
+        text: String,
+        error: Box<dyn Error + Send + Sync>,
+    },
+    /// All fields were parsed, but the input had more. `text` is the first of those.
+    TooMany { text: String },
+}
+
+impl fmt::Display for ParseStructError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let field = self.field.unwrap_or("");
+        match self.kind {
+            ParseStructErrorKind::Missing => {
+                write!(f, "missing field `{}` of `{}`", field, self.struct_name)
+            }
+            ParseStructErrorKind::Invalid {
+                ref text,
+                ref error,
+            } => write!(
+                f,
+                "could not parse field `{}` of `{}` from {:?}: {}",
+                field, self.struct_name, text, error
+            ),
+            ParseStructErrorKind::TooMany { ref text } => write!(
+                f,
+                "too many fields for `{}`, starting at {:?}",
+                self.struct_name, text
+            ),
+        }
+    }
+}
+
+impl Error for ParseStructError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.kind {
+            ParseStructErrorKind::Invalid { ref error, .. } => Some(&**error),
+            ParseStructErrorKind::Missing | ParseStructErrorKind::TooMany { .. } => None,
+        }
+    }
+}
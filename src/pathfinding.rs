@@ -0,0 +1,307 @@
+//! Shortest path searches over any kind of node, like a [Position](../movement/struct.Position.html) on a map.
+//!
+//! Every search takes the start nodes, a closure returning the neighbours of a node, and a closure deciding whether a node is a goal.
+//! On success, the cost to reach the first goal found is returned together with the path, starting at one of the start nodes and ending at the goal.
+use absolute::Absolute;
+use movement::{ManhattenDst, Position};
+use std::cmp;
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+
+/// Walks the `parents` map back from `goal`, and returns the path from a start node to `goal`.
+fn reconstruct<N, C>(parents: &HashMap<N, (Option<N>, C)>, goal: N) -> Vec<N>
+where
+    N: Eq + Hash + Clone,
+{
+    let mut path = vec![goal];
+    while let Some(&(Some(ref parent), _)) = parents.get(&path[path.len() - 1]) {
+        path.push(parent.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth-first search, where every step costs 1.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::grid::Grid;
+/// use libaoc::movement::Position;
+/// use libaoc::pathfinding::bfs;
+/// fn main() {
+///     let maze: Grid<bool> = "...\n##.\n...".parse().unwrap();
+///     let goal = Position::new(0, 2);
+///
+///     let (steps, path) = bfs(
+///         vec![Position::new(0, 0)],
+///         |&pos| maze.neighbours(pos).filter(|&n| !maze[n]).collect::<Vec<_>>(),
+///         |&pos| pos == goal,
+///     )
+///     .unwrap();
+///
+///     assert_eq!(6, steps);
+///     assert_eq!(7, path.len());
+///     assert_eq!(Some(&goal), path.last());
+/// }
+/// ```
+pub fn bfs<N, S, FN, IN, FG>(
+    starts: S,
+    mut neighbours: FN,
+    mut is_goal: FG,
+) -> Option<(usize, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    S: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut parents: HashMap<N, (Option<N>, usize)> = HashMap::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert((None, 0));
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, dist)) = queue.pop_front() {
+        if is_goal(&node) {
+            return Some((dist, reconstruct(&parents, node)));
+        }
+
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert((Some(node.clone()), dist + 1));
+                queue.push_back((next, dist + 1));
+            }
+        }
+    }
+    None
+}
+
+/// An item on the priority queue. Ordered by `estimate`, reversed, so the BinaryHeap pops the cheapest item first.
+struct Candidate<N, C> {
+    estimate: C,
+    cost: C,
+    node: N,
+}
+
+impl<N, C: Ord> PartialEq for Candidate<N, C> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<N, C: Ord> Eq for Candidate<N, C> {}
+
+impl<N, C: Ord> PartialOrd for Candidate<N, C> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N, C: Ord> Ord for Candidate<N, C> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .estimate
+            .cmp(&self.estimate)
+            .then_with(|| self.cost.cmp(&other.cost))
+    }
+}
+
+/// Dijkstra's algorithm. The neighbour closure returns the neighbours together with the cost to move there.
+/// Costs must not be negative. `C::default()` is used as the cost of a start node.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::pathfinding::dijkstra;
+/// fn main() {
+///     // 'a' -> 'c' directly is more expensive than going through 'b'.
+///     let edges = |&node: &char| match node {
+///         'a' => vec![('b', 1), ('c', 10)],
+///         'b' => vec![('c', 2)],
+///         _ => vec![],
+///     };
+///
+///     assert_eq!(Some((3, vec!['a', 'b', 'c'])), dijkstra(vec!['a'], edges, |&n| n == 'c'));
+///     assert_eq!(None, dijkstra(vec!['c'], edges, |&n| n == 'a'));
+/// }
+/// ```
+pub fn dijkstra<N, C, S, FN, IN, FG>(starts: S, neighbours: FN, is_goal: FG) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search. Same as [dijkstra](fn.dijkstra.html), but the search is guided by `heuristic`,
+/// which estimates the remaining cost from a node to the nearest goal.
+/// The returned path is only guaranteed to be the shortest if the heuristic never overestimates.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::pathfinding::astar;
+/// fn main() {
+///     // walk the number line from 0 to 7, with steps of 1 or 3.
+///     let (cost, path) = astar(
+///         vec![0i32],
+///         |&n| vec![(n + 1, 1), (n + 3, 1)],
+///         |&n| (7 - n).abs() / 3,
+///         |&n| n == 7,
+///     )
+///     .unwrap();
+///
+///     assert_eq!(3, cost);
+///     assert_eq!(4, path.len());
+/// }
+/// ```
+pub fn astar<N, C, S, FN, IN, FH, FG>(
+    starts: S,
+    mut neighbours: FN,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(C, Vec<N>)>
+where
+    N: Eq + Hash + Clone,
+    C: Ord + Copy + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    FN: FnMut(&N) -> IN,
+    IN: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    let mut best: HashMap<N, (Option<N>, C)> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            entry.insert((None, C::default()));
+            heap.push(Candidate {
+                estimate: heuristic(&start),
+                cost: C::default(),
+                node: start,
+            });
+        }
+    }
+
+    while let Some(Candidate { cost, node, .. }) = heap.pop() {
+        // a cheaper way to this node was found after this candidate was pushed.
+        if best.get(&node).is_some_and(|&(_, known)| cost > known) {
+            continue;
+        }
+
+        if is_goal(&node) {
+            return Some((cost, reconstruct(&best, node)));
+        }
+
+        for (next, step) in neighbours(&node) {
+            let next_cost = cost + step;
+            let improved = match best.entry(next.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert((Some(node.clone()), next_cost));
+                    true
+                }
+                Entry::Occupied(mut entry) => {
+                    if next_cost < entry.get().1 {
+                        entry.insert((Some(node.clone()), next_cost));
+                        true
+                    } else {
+                        false
+                    }
+                }
+            };
+
+            if improved {
+                heap.push(Candidate {
+                    estimate: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                });
+            }
+        }
+    }
+    None
+}
+
+/// A* search towards a single `goal`, using the [ManhattenDst](../movement/trait.ManhattenDst.html) between a node and the goal as heuristic.
+/// This is the usual choice on a map where every step moves one unit up, down, left or right.
+///
+/// The heuristic is computed in the cost type, which is also the type of the coordinates.
+/// It is measured axis by axis from the smaller coordinate, so it never underflows on unsigned positions.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::movement::{Direction, Position};
+/// use libaoc::pathfinding::astar_manhattan;
+/// fn main() {
+///     let wall = |pos: &Position<i32>| *pos.get_ref_x() == 1 && *pos.get_ref_y() < 3;
+///
+///     let neighbours = |pos: &Position<i32>| {
+///         let mut next = Vec::new();
+///         for dir in &[Direction::Up, Direction::Down, Direction::Left, Direction::Right] {
+///             let mut n = *pos;
+///             n.change(dir, 1);
+///             if !wall(&n) {
+///                 next.push((n, 1));
+///             }
+///         }
+///         next
+///     };
+///
+///     let (cost, _) = astar_manhattan(vec![Position::new(0, 0)], neighbours, Position::new(2, 0)).unwrap();
+///     assert_eq!(8, cost);
+/// }
+/// ```
+pub fn astar_manhattan<C, S, FN, IN>(
+    starts: S,
+    neighbours: FN,
+    goal: Position<C>,
+) -> Option<(C, Vec<Position<C>>)>
+where
+    C: Ord + Copy + Hash + Add<Output = C> + Sub<Output = C> + Default + Absolute,
+    S: IntoIterator<Item = Position<C>>,
+    FN: FnMut(&Position<C>) -> IN,
+    IN: IntoIterator<Item = (Position<C>, C)>,
+{
+    let axis = |a: C, b: C| cmp::max(a, b) - cmp::min(a, b);
+    let (goal_x, goal_y) = goal.into();
+    astar(
+        starts,
+        neighbours,
+        |&node| {
+            let (x, y) = node.into();
+            (axis(x, goal_x), axis(y, goal_y)).manhattendst()
+        },
+        |node| *node == goal,
+    )
+}
+
+/// The old spelling of [astar_manhattan](fn.astar_manhattan.html).
+#[deprecated(note = "renamed to `astar_manhattan`")]
+#[inline]
+pub fn astar_manhatten<C, S, FN, IN>(
+    starts: S,
+    neighbours: FN,
+    goal: Position<C>,
+) -> Option<(C, Vec<Position<C>>)>
+where
+    C: Ord + Copy + Hash + Add<Output = C> + Sub<Output = C> + Default + Absolute,
+    S: IntoIterator<Item = Position<C>>,
+    FN: FnMut(&Position<C>) -> IN,
+    IN: IntoIterator<Item = (Position<C>, C)>,
+{
+    astar_manhattan(starts, neighbours, goal)
+}
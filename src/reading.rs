@@ -1,53 +1,823 @@
+use std::env;
+use std::error::Error;
 use std::ffi::OsStr;
-use std::fs::File;
+use std::fmt;
+use std::fs::{self, File};
 use std::io::prelude::*;
 use std::io::{self, BufReader};
-use std::path::Path;
+use std::marker::PhantomData;
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
-fn into_buf_reader<S: AsRef<OsStr>>(s: S) -> Result<BufReader<File>, io::Error> {
-    let path: &Path = Path::new(s.as_ref());
-    let f = File::open(path)?;
-    Ok(BufReader::new(f))
-}
-/// Opens a file, an reads it to whatever type it was called on.
+/// Opens a file, and reads it to whatever type it was called on.
+///
+/// `String` reads the file as text, [Bytes](struct.Bytes.html) reads the raw bytes,
+/// and `Vec<T>` parses every whitespace or newline separated value into a `T`.
 /// # Examples
 /// ```
 /// extern crate libaoc;
-/// use libaoc::reading::ReadFile;
+/// use libaoc::reading::{Bytes, ReadError, ReadFile};
+/// use libaoc::Error;
+/// use std::env;
+/// use std::fs;
+/// use std::num::ParseIntError;
+/// use std::process;
 /// fn main() {
-///     let puzzle = match Vec::<u8>::read_file(r"test.txt") {
-///         Ok(content) => content,
-///         Err(_) => Vec::new(),
-///     };
-///     assert_eq!(b"hello! this is a test!"[..], puzzle[..]);
+///     let path = env::temp_dir().join(format!("libaoc-read-file-doctest-{}.txt", process::id()));
+///     fs::write(&path, "1 2\n3\n").unwrap();
+///
+///     assert_eq!("1 2\n3\n", String::read_file(&path).unwrap());
+///     assert_eq!(Bytes(b"1 2\n3\n".to_vec()), Bytes::read_file(&path).unwrap());
+///     assert_eq!(vec![1, 2, 3], Vec::<u8>::read_file(&path).unwrap());
+///
+///     // the index of the value that could not be parsed is reported.
+///     fs::write(&path, "1 2 x").unwrap();
+///     match Vec::<u8>::read_file(&path) {
+///         Err(Error::Parse(error)) => match error.downcast_ref::<ReadError<ParseIntError>>() {
+///             Some(&ReadError::Parse { index, .. }) => assert_eq!(2, index),
+///             _ => panic!(),
+///         },
+///         _ => panic!(),
+///     }
+///
+///     // every type reports invalid UTF-8 the same way.
+///     fs::write(&path, b"1 \xff").unwrap();
+///     match Vec::<u8>::read_file(&path) {
+///         Err(Error::Utf8(_)) => {}
+///         _ => panic!(),
+///     }
+///
+///     // as well as a file that can not be opened.
+///     fs::remove_file(&path).unwrap();
+///     match Vec::<u8>::read_file(&path) {
+///         Err(Error::Io(_)) => {}
+///         _ => panic!(),
+///     }
 /// }
 /// ```
-pub trait ReadFile {
-    type Content;
+pub trait ReadFile: Sized {
+    /// The Errortype that gets returned when reading fails.
+    /// This can always be created from an `io::Error`.
+    type Error: From<io::Error>;
+
+    /// Reads everything that is left in `source`.
+    fn read_from(source: Source) -> Result<Self, Self::Error>;
 
-    fn read_file<S: AsRef<OsStr>>(s: S) -> Result<Self::Content, io::Error>;
+    /// Opens `s` with [Source::open](struct.Source.html#method.open), and reads it.
+    /// A path of `-` reads stdin instead, and a file is read through a buffer.
+    /// To memory-map a large file, open it with the unsafe [Source::open_mapped](struct.Source.html#method.open_mapped) and call [read_from](#tymethod.read_from).
+    #[inline]
+    fn read_file<S: AsRef<OsStr>>(s: S) -> Result<Self, Self::Error> {
+        Self::read_from(Source::open(s)?)
+    }
 }
 
 impl ReadFile for String {
-    type Content = String;
+    type Error = ::Error;
 
     #[inline]
-    fn read_file<S: AsRef<OsStr>>(path: S) -> Result<Self::Content, io::Error> {
-        let mut s = String::new();
-        let mut bufreader = into_buf_reader(path)?;
-        bufreader.read_to_string(&mut s)?;
-        Ok(s)
+    fn read_from(source: Source) -> Result<Self, Self::Error> {
+        let Bytes(bytes) = Bytes::read_from(source)?;
+        Ok(String::from_utf8(bytes)?)
     }
 }
 
-impl<T> ReadFile for Vec<T> {
-    type Content = Vec<u8>;
+/// The raw bytes of a file.
+/// This is what `Vec<u8>` used to read to, before `Vec<T>` parsed its content.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Bytes(pub Vec<u8>);
+
+impl ReadFile for Bytes {
+    type Error = ::Error;
 
     #[inline]
-    fn read_file<S: AsRef<OsStr>>(path: S) -> Result<Self::Content, io::Error> {
+    fn read_from(mut source: Source) -> Result<Self, Self::Error> {
         let mut v: Vec<u8> = Vec::new();
-        let mut bufreader = into_buf_reader(path)?;
-        bufreader.read_to_end(&mut v)?;
-        Ok(v)
+        source.read_to_end(&mut v)?;
+        Ok(Bytes(v))
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    #[inline]
+    fn from(bytes: Bytes) -> Vec<u8> {
+        bytes.0
+    }
+}
+
+impl AsRef<[u8]> for Bytes {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// A value that could not be parsed is reported as an `Error::Parse` holding a [ReadError](enum.ReadError.html), which tells its index.
+impl<T> ReadFile for Vec<T>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    type Error = ::Error;
+
+    fn read_from(source: Source) -> Result<Self, Self::Error> {
+        let content = String::read_from(source)?;
+        content
+            .split_whitespace()
+            .enumerate()
+            .map(|(index, value)| {
+                value
+                    .parse()
+                    .map_err(|error| ::Error::parse(ReadError::Parse { index, error }))
+            })
+            .collect()
+    }
+}
+
+/// The error returned when reading a file to a parsed type fails.
+/// [ReadFile](trait.ReadFile.html) for `Vec<T>` reports it inside an [Error](../enum.Error.html).
+#[derive(Debug)]
+pub enum ReadError<E> {
+    /// The file could not be opened or read.
+    Io(io::Error),
+    /// The value at `index` could not be parsed. `index` counts from 0.
+    Parse { index: usize, error: E },
+}
+
+impl<E> From<io::Error> for ReadError<E> {
+    #[inline]
+    fn from(error: io::Error) -> ReadError<E> {
+        ReadError::Io(error)
     }
 }
+
+impl<E: fmt::Display> fmt::Display for ReadError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ReadError::Io(ref error) => write!(f, "could not read the file: {}", error),
+            ReadError::Parse { index, ref error } => {
+                write!(f, "could not parse value {}: {}", index, error)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ReadError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ReadError::Io(ref error) => Some(error),
+            ReadError::Parse { ref error, .. } => Some(error),
+        }
+    }
+}
+
+/// A good threshold to pass to [Source::open_mapped](struct.Source.html#method.open_mapped).
+/// Below this size, mapping a file is not worth the system calls.
+pub const MMAP_THRESHOLD: u64 = 16 * 1024 * 1024;
+
+/// Where a [Source](struct.Source.html) reads from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SourceKind {
+    /// The standard input of the process.
+    Stdin,
+    /// A file, read through a buffer.
+    Buffered,
+    /// A file, mapped into memory.
+    Mapped,
+}
+
+/// Something to read puzzle input from: stdin, a buffered file, or a memory-mapped file.
+/// Implements `Read` and `BufRead`, so it can be passed to [lines()](fn.lines.html) and [records()](fn.records.html).
+///
+/// Files are only memory-mapped when asked for with the unsafe [open_mapped](#method.open_mapped).
+/// Memory-mapping is only supported on 64-bit unix. Elsewhere, files are always read through a buffer.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::reading::{ReadFile, Source, SourceKind};
+/// use std::env;
+/// use std::fs;
+/// use std::process;
+/// fn main() {
+///     let path = env::temp_dir().join(format!("libaoc-source-doctest-{}.txt", process::id()));
+///     fs::write(&path, "hello! this is a test!").unwrap();
+///
+///     let source = Source::open(&path).unwrap();
+///     assert_eq!(SourceKind::Buffered, source.kind());
+///
+///     // map every file, no matter how small.
+///     // nothing changes the file while `source` is alive, so this is sound.
+///     let source = unsafe { Source::open_mapped(&path, 0).unwrap() };
+///     if cfg!(all(unix, target_pointer_width = "64")) {
+///         assert_eq!(SourceKind::Mapped, source.kind());
+///     }
+///     assert_eq!("hello! this is a test!", String::read_from(source).unwrap());
+///
+///     assert_eq!(SourceKind::Stdin, Source::open("-").unwrap().kind());
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Source {
+    inner: SourceInner,
+}
+
+#[derive(Debug)]
+enum SourceInner {
+    Stdin(io::StdinLock<'static>),
+    Buffered(BufReader<File>),
+    #[cfg(all(unix, target_pointer_width = "64"))]
+    Mapped {
+        map: mmap::Mmap,
+        pos: usize,
+    },
+}
+
+impl Source {
+    /// Opens `path`. A path of `-` reads stdin, and any other file is read through a buffer.
+    #[inline]
+    pub fn open<S: AsRef<OsStr>>(path: S) -> Result<Source, io::Error> {
+        let path = Path::new(path.as_ref());
+        if path == Path::new("-") {
+            return Ok(Source::stdin());
+        }
+        Ok(Source {
+            inner: SourceInner::Buffered(BufReader::new(File::open(path)?)),
+        })
+    }
+
+    /// Same as [open](#method.open), but memory-maps files of at least `threshold` bytes.
+    /// [MMAP_THRESHOLD](constant.MMAP_THRESHOLD.html) is a good default, pass 0 to map every file.
+    /// Smaller files, and every file on platforms without support for mapping, are read through a buffer.
+    ///
+    /// # Safety
+    /// The bytes of a mapped file are handed out as `&[u8]` without being copied.
+    /// The caller must make sure that the file is not truncated or written to, by this or any other process, until the Source is dropped.
+    /// Truncating it makes reading the missing part crash the process (`SIGBUS`), and writing to it changes data that is assumed to be immutable.
+    pub unsafe fn open_mapped<S: AsRef<OsStr>>(
+        path: S,
+        threshold: u64,
+    ) -> Result<Source, io::Error> {
+        let path = Path::new(path.as_ref());
+        if path == Path::new("-") {
+            return Ok(Source::stdin());
+        }
+
+        let file = File::open(path)?;
+        let len = file.metadata()?.len();
+        if len >= threshold {
+            if let Some(source) = Source::map(&file, len)? {
+                return Ok(source);
+            }
+        }
+        Ok(Source {
+            inner: SourceInner::Buffered(BufReader::new(file)),
+        })
+    }
+
+    /// Returns a Source reading the standard input of the process.
+    /// Stdin stays locked until the Source is dropped.
+    #[inline]
+    pub fn stdin() -> Source {
+        Source {
+            inner: SourceInner::Stdin(io::stdin().lock()),
+        }
+    }
+
+    /// Returns where this Source reads from.
+    #[inline]
+    pub fn kind(&self) -> SourceKind {
+        match self.inner {
+            SourceInner::Stdin(_) => SourceKind::Stdin,
+            SourceInner::Buffered(_) => SourceKind::Buffered,
+            #[cfg(all(unix, target_pointer_width = "64"))]
+            SourceInner::Mapped { .. } => SourceKind::Mapped,
+        }
+    }
+
+    /// Callers must uphold the contract of [open_mapped](#method.open_mapped).
+    #[cfg(all(unix, target_pointer_width = "64"))]
+    #[inline]
+    unsafe fn map(file: &File, len: u64) -> Result<Option<Source>, io::Error> {
+        let map = mmap::Mmap::new(file, len as usize)?;
+        Ok(Some(Source {
+            inner: SourceInner::Mapped { map, pos: 0 },
+        }))
+    }
+
+    #[cfg(not(all(unix, target_pointer_width = "64")))]
+    #[inline]
+    unsafe fn map(_: &File, _: u64) -> Result<Option<Source>, io::Error> {
+        Ok(None)
+    }
+}
+
+impl Read for Source {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self.inner {
+            SourceInner::Stdin(ref mut stdin) => stdin.read(buf),
+            SourceInner::Buffered(ref mut file) => file.read(buf),
+            #[cfg(all(unix, target_pointer_width = "64"))]
+            SourceInner::Mapped { .. } => {
+                let n = {
+                    let available = self.fill_buf()?;
+                    let n = available.len().min(buf.len());
+                    buf[..n].copy_from_slice(&available[..n]);
+                    n
+                };
+                self.consume(n);
+                Ok(n)
+            }
+        }
+    }
+}
+
+impl BufRead for Source {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        match self.inner {
+            SourceInner::Stdin(ref mut stdin) => stdin.fill_buf(),
+            SourceInner::Buffered(ref mut file) => file.fill_buf(),
+            #[cfg(all(unix, target_pointer_width = "64"))]
+            SourceInner::Mapped { ref map, pos } => Ok(&map.as_slice()[pos..]),
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        match self.inner {
+            SourceInner::Stdin(ref mut stdin) => stdin.consume(amt),
+            SourceInner::Buffered(ref mut file) => file.consume(amt),
+            #[cfg(all(unix, target_pointer_width = "64"))]
+            SourceInner::Mapped {
+                ref map,
+                ref mut pos,
+            } => *pos = (*pos + amt).min(map.as_slice().len()),
+        }
+    }
+}
+
+/// A read-only memory map, declared by hand so the crate keeps having no dependencies.
+#[cfg(all(unix, target_pointer_width = "64"))]
+mod mmap {
+    use std::fs::File;
+    use std::io;
+    use std::os::raw::{c_int, c_void};
+    use std::os::unix::io::AsRawFd;
+    use std::ptr;
+    use std::slice;
+
+    // the same on every unix.
+    const PROT_READ: c_int = 1;
+    const MAP_PRIVATE: c_int = 2;
+
+    extern "C" {
+        fn mmap(
+            addr: *mut c_void,
+            len: usize,
+            prot: c_int,
+            flags: c_int,
+            fd: c_int,
+            offset: i64,
+        ) -> *mut c_void;
+        fn munmap(addr: *mut c_void, len: usize) -> c_int;
+    }
+
+    #[derive(Debug)]
+    pub struct Mmap {
+        ptr: *mut c_void,
+        len: usize,
+    }
+
+    impl Mmap {
+        /// Maps the first `len` bytes of `file`. An empty file can not be mapped, so nothing is mapped for `len == 0`.
+        ///
+        /// # Safety
+        /// The file must not be truncated or written to while the map is alive, see `Source::open_mapped`.
+        pub unsafe fn new(file: &File, len: usize) -> io::Result<Mmap> {
+            if len == 0 {
+                return Ok(Mmap {
+                    ptr: ptr::null_mut(),
+                    len: 0,
+                });
+            }
+
+            let ptr = mmap(
+                ptr::null_mut(),
+                len,
+                PROT_READ,
+                MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            );
+            // MAP_FAILED is (void *) -1.
+            if ptr as usize == usize::MAX {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(Mmap { ptr, len })
+        }
+
+        #[inline]
+        pub fn as_slice(&self) -> &[u8] {
+            if self.len == 0 {
+                return &[];
+            }
+            // the mapping is valid and readable for `len` bytes until it is dropped,
+            // and the contract of `new` keeps the bytes from changing.
+            unsafe { slice::from_raw_parts(self.ptr as *const u8, self.len) }
+        }
+    }
+
+    impl Drop for Mmap {
+        fn drop(&mut self) {
+            if self.len != 0 {
+                unsafe {
+                    munmap(self.ptr, self.len);
+                }
+            }
+        }
+    }
+
+    // the mapping is read-only, and owned by this struct alone.
+    unsafe impl Send for Mmap {}
+    unsafe impl Sync for Mmap {}
+}
+
+/// Returns a lazy iterator over the lines of `reader`, without the line endings.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::reading::lines;
+/// use std::io::Cursor;
+/// fn main() {
+///     let mut iter = lines(Cursor::new("1\r\n2\n\n3\n"));
+///
+///     assert_eq!("1", iter.next().unwrap().unwrap());
+///     assert_eq!("2", iter.next().unwrap().unwrap());
+///     assert_eq!("", iter.next().unwrap().unwrap());
+///     assert_eq!("3", iter.next().unwrap().unwrap());
+///     assert!(iter.next().is_none());
+/// }
+/// ```
+#[inline]
+pub fn lines<R: BufRead>(reader: R) -> Records<R> {
+    records(reader, b'\n')
+}
+
+/// Returns a lazy iterator over the records of `reader` that are separated by `delimiter`.
+/// The delimiter is not part of the records, and neither is the line ending at the end of the input.
+/// Line endings anywhere else are kept, unless `delimiter` is `b'\n'`.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::reading::records;
+/// use std::io::Cursor;
+/// fn main() {
+///     // a delimiter after the last record is fine too.
+///     let mut iter = records(Cursor::new("1,2,-3,\n"), b',').parse::<i32>();
+///
+///     assert_eq!(Some(1), iter.next().map(Result::unwrap));
+///     assert_eq!(Some(2), iter.next().map(Result::unwrap));
+///     assert_eq!(Some(-3), iter.next().map(Result::unwrap));
+///     assert!(iter.next().is_none());
+/// }
+/// ```
+#[inline]
+pub fn records<R: BufRead>(reader: R, delimiter: u8) -> Records<R> {
+    Records {
+        reader,
+        delimiter,
+        buf: Vec::new(),
+    }
+}
+
+/// Opens a file, and returns a lazy iterator that parses every line into `U`.
+/// The file is opened with [Source::open](struct.Source.html#method.open), so a path of `-` reads stdin, and a file is read through a buffer.
+/// To memory-map a large file, pass a Source from the unsafe [Source::open_mapped](struct.Source.html#method.open_mapped) to [lines()](fn.lines.html).
+/// This is the streaming counterpart of calling [TryConvert](../convert/trait.TryConvert.html) on the lines of a file.
+/// Every error carries the number of the line it occured on, counted from 1.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::reading::{read_lines, LineError};
+/// use std::env;
+/// use std::fs;
+/// use std::process;
+/// fn main() {
+///     let path = env::temp_dir().join(format!("libaoc-read-lines-doctest-{}.txt", process::id()));
+///     fs::write(&path, "10\n-20\nthirty\n40\n").unwrap();
+///
+///     let mut iter = read_lines::<i64>(&path).unwrap();
+///     assert_eq!(Some(10), iter.next().map(Result::unwrap));
+///     assert_eq!(Some(-20), iter.next().map(Result::unwrap));
+///
+///     match iter.next() {
+///         Some(Err(LineError::Parse { line, text, .. })) => {
+///             assert_eq!(3, line);
+///             assert_eq!("thirty", text);
+///         }
+///         _ => panic!(),
+///     }
+///
+///     // parsing continues after an error.
+///     assert_eq!(Some(40), iter.next().map(Result::unwrap));
+///     assert!(iter.next().is_none());
+/// }
+/// ```
+#[inline]
+pub fn read_lines<U: FromStr>(
+    path: impl AsRef<OsStr>,
+) -> Result<ParsedRecords<Source, U>, io::Error> {
+    Ok(lines(Source::open(path)?).parse())
+}
+
+/// Same as [read_lines](fn.read_lines.html), but for records separated by `delimiter`.
+#[inline]
+pub fn read_records<U: FromStr>(
+    path: impl AsRef<OsStr>,
+    delimiter: u8,
+) -> Result<ParsedRecords<Source, U>, io::Error> {
+    Ok(records(Source::open(path)?, delimiter).parse())
+}
+
+/// The Iterator that gets returned from [lines()](fn.lines.html) and [records()](fn.records.html)
+#[derive(Debug)]
+pub struct Records<R> {
+    reader: R,
+    delimiter: u8,
+    buf: Vec<u8>,
+}
+
+impl<R: BufRead> Records<R> {
+    /// Parses every record into `U`.
+    #[inline]
+    pub fn parse<U: FromStr>(self) -> ParsedRecords<R, U> {
+        ParsedRecords {
+            records: self,
+            line: 0,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Records<R> {
+    type Item = Result<String, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.buf.clear();
+        match self.reader.read_until(self.delimiter, &mut self.buf) {
+            Ok(0) => None,
+            Ok(_) => {
+                let mut strip_cr = self.delimiter == b'\n';
+                let mut at_end = false;
+                if self.buf.last() == Some(&self.delimiter) {
+                    self.buf.pop();
+                } else if self.buf.last() == Some(&b'\n') {
+                    // no delimiter, so this is the last record. The line ending of the input is not part of it.
+                    self.buf.pop();
+                    strip_cr = true;
+                    at_end = true;
+                }
+                if strip_cr && self.buf.last() == Some(&b'\r') {
+                    self.buf.pop();
+                }
+                if at_end && self.buf.is_empty() {
+                    // only the line ending was left after the last delimiter.
+                    return None;
+                }
+                Some(
+                    String::from_utf8(mem::take(&mut self.buf))
+                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+                )
+            }
+            Err(e) => Some(Err(e)),
+        }
+    }
+}
+
+/// The Iterator that gets returned from [Records::parse()](struct.Records.html#method.parse)
+#[derive(Debug)]
+pub struct ParsedRecords<R, U> {
+    records: Records<R>,
+    line: usize,
+    marker: PhantomData<fn() -> U>,
+}
+
+impl<R: BufRead, U: FromStr> Iterator for ParsedRecords<R, U> {
+    type Item = Result<U, LineError<U::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = self.records.next()?;
+        self.line += 1;
+        let line = self.line;
+
+        Some(match record {
+            Ok(text) => text
+                .parse()
+                .map_err(|error| LineError::Parse { line, text, error }),
+            Err(error) => Err(LineError::Io { line, error }),
+        })
+    }
+}
+
+/// The error returned when reading or parsing a line fails.
+/// `line` is the number of the line or record, counted from 1.
+#[derive(Debug)]
+pub enum LineError<E> {
+    /// The line could not be read.
+    Io { line: usize, error: io::Error },
+    /// The line was read, but could not be parsed.
+    Parse { line: usize, text: String, error: E },
+}
+
+impl<E> LineError<E> {
+    /// Returns the number of the line the error occured on.
+    #[inline]
+    pub fn line(&self) -> usize {
+        match *self {
+            LineError::Io { line, .. } | LineError::Parse { line, .. } => line,
+        }
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for LineError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            LineError::Io { line, ref error } => {
+                write!(f, "could not read line {}: {}", line, error)
+            }
+            LineError::Parse {
+                line,
+                ref text,
+                ref error,
+            } => write!(f, "could not parse line {} ({:?}): {}", line, text, error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for LineError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            LineError::Io { ref error, .. } => Some(error),
+            LineError::Parse { ref error, .. } => Some(error),
+        }
+    }
+}
+
+/// Finds the puzzle input of a (year, day), so binaries don't have to hard-code paths.
+///
+/// Inputs are stored as `<year>/day<day>.txt`, with the day padded to 2 digits.
+/// They are first looked up in the input directory, and then in the cache directory.
+/// Nothing is ever downloaded, so this works offline.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::reading::InputResolver;
+/// use std::{env, process};
+/// fn main() {
+///     let root = env::temp_dir().join(format!("libaoc-resolver-doctest-{}", process::id()));
+///     let resolver = InputResolver::new(root.join("input"), root.join("cache"));
+///
+///     let cached = resolver.store_in_cache(2018, 3, "#1 @ 1,3: 4x4").unwrap();
+///     assert_eq!(cached, resolver.resolve(2018, 3).unwrap());
+///     assert_eq!("#1 @ 1,3: 4x4", resolver.read::<String>(2018, 3).unwrap());
+///
+///     let missing = resolver.resolve(2018, 4).unwrap_err();
+///     assert!(missing.to_string().contains("day04.txt"));
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct InputResolver {
+    input_dir: PathBuf,
+    cache_dir: PathBuf,
+}
+
+impl InputResolver {
+    /// The environment variable that overrides the input directory.
+    pub const INPUT_DIR_VAR: &'static str = "AOC_INPUT_DIR";
+
+    /// The environment variable that overrides the cache directory.
+    pub const CACHE_DIR_VAR: &'static str = "AOC_CACHE_DIR";
+
+    /// The input directory used when [INPUT_DIR_VAR](#associatedconstant.INPUT_DIR_VAR) is not set, relative to the working directory.
+    pub const DEFAULT_INPUT_DIR: &'static str = "input";
+
+    /// The cache directory used when [CACHE_DIR_VAR](#associatedconstant.CACHE_DIR_VAR) is not set, relative to the working directory.
+    pub const DEFAULT_CACHE_DIR: &'static str = ".aoc-cache";
+
+    /// Returns a new InputResolver that looks in `input_dir` first, and then in `cache_dir`.
+    #[inline]
+    pub fn new<P, Q>(input_dir: P, cache_dir: Q) -> InputResolver
+    where
+        P: Into<PathBuf>,
+        Q: Into<PathBuf>,
+    {
+        InputResolver {
+            input_dir: input_dir.into(),
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    /// Returns a new InputResolver using the directories from the environment, or the project defaults if they are not set.
+    #[inline]
+    pub fn from_env() -> InputResolver {
+        let dir =
+            |var, default| env::var_os(var).map_or_else(|| PathBuf::from(default), PathBuf::from);
+        InputResolver {
+            input_dir: dir(Self::INPUT_DIR_VAR, Self::DEFAULT_INPUT_DIR),
+            cache_dir: dir(Self::CACHE_DIR_VAR, Self::DEFAULT_CACHE_DIR),
+        }
+    }
+
+    /// Returns the input directory.
+    #[inline]
+    pub fn input_dir(&self) -> &Path {
+        &self.input_dir
+    }
+
+    /// Returns the cache directory.
+    #[inline]
+    pub fn cache_dir(&self) -> &Path {
+        &self.cache_dir
+    }
+
+    /// Returns the path of an input, relative to the input or cache directory.
+    #[inline]
+    pub fn relative_path(year: u16, day: u8) -> PathBuf {
+        Path::new(&year.to_string()).join(format!("day{:02}.txt", day))
+    }
+
+    /// Returns the path of the input of (year, day), or an error naming every path that was tried.
+    pub fn resolve(&self, year: u16, day: u8) -> Result<PathBuf, MissingInput> {
+        let relative = Self::relative_path(year, day);
+        let searched = vec![
+            self.input_dir.join(&relative),
+            self.cache_dir.join(&relative),
+        ];
+
+        match searched.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => Err(MissingInput {
+                year,
+                day,
+                searched,
+            }),
+        }
+    }
+
+    /// Resolves the input of (year, day), and reads it to whatever type it was called with.
+    /// A missing input is reported as an error of kind `NotFound`, with the [MissingInput](struct.MissingInput.html) as its inner error.
+    #[inline]
+    pub fn read<R: ReadFile>(&self, year: u16, day: u8) -> Result<R, R::Error> {
+        let path = self
+            .resolve(year, day)
+            .map_err(|missing| io::Error::new(io::ErrorKind::NotFound, missing))?;
+        R::read_file(path)
+    }
+
+    /// Writes `content` as the input of (year, day) to the cache directory, and returns the path it was written to.
+    pub fn store_in_cache<C: AsRef<[u8]>>(
+        &self,
+        year: u16,
+        day: u8,
+        content: C,
+    ) -> Result<PathBuf, io::Error> {
+        let path = self.cache_dir.join(Self::relative_path(year, day));
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, content)?;
+        Ok(path)
+    }
+}
+
+impl Default for InputResolver {
+    #[inline]
+    fn default() -> InputResolver {
+        InputResolver::from_env()
+    }
+}
+
+/// The error returned when the input of a (year, day) could not be found.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MissingInput {
+    pub year: u16,
+    pub day: u8,
+    /// Every path that was tried, in order.
+    pub searched: Vec<PathBuf>,
+}
+
+impl fmt::Display for MissingInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "no input for day {} of year {}, looked in",
+            self.day, self.year
+        )?;
+        for (idx, path) in self.searched.iter().enumerate() {
+            let sep = if idx == 0 { " " } else { " and " };
+            write!(f, "{}{}", sep, path.display())?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for MissingInput {}
@@ -0,0 +1,343 @@
+use reading::InputResolver;
+use std::collections::BTreeMap;
+use std::error::Error;
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// The solution to the puzzle of a single day.
+/// Both parts get the puzzle input, and return something that can be displayed as the answer.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::runner::Solution;
+///
+/// struct Day01;
+///
+/// impl Solution for Day01 {
+///     type Part1 = i64;
+///     type Part2 = String;
+///
+///     fn part1(&self, input: &str) -> i64 {
+///         input.lines().map(|line| line.parse::<i64>().unwrap()).sum()
+///     }
+///
+///     fn part2(&self, input: &str) -> String {
+///         format!("{} lines", input.lines().count())
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(6, Day01.part1("1\n2\n3"));
+///     assert_eq!("3 lines", Day01.part2("1\n2\n3"));
+/// }
+/// ```
+pub trait Solution {
+    /// The answer to part 1.
+    type Part1: Display;
+
+    /// The answer to part 2.
+    type Part2: Display;
+
+    /// Solves part 1 of the puzzle.
+    fn part1(&self, input: &str) -> Self::Part1;
+
+    /// Solves part 2 of the puzzle.
+    fn part2(&self, input: &str) -> Self::Part2;
+}
+
+/// A [Solution](trait.Solution.html) with the answer types erased, so solutions of different days can be stored together.
+trait ErasedSolution {
+    fn run_part(&self, part: u8, input: &str) -> (String, Duration);
+}
+
+impl<S: Solution> ErasedSolution for S {
+    fn run_part(&self, part: u8, input: &str) -> (String, Duration) {
+        let start = Instant::now();
+        if part == 1 {
+            let answer = self.part1(input);
+            (answer.to_string(), start.elapsed())
+        } else {
+            let answer = self.part2(input);
+            (answer.to_string(), start.elapsed())
+        }
+    }
+}
+
+type InputLoader = Box<dyn Fn(u16, u8) -> io::Result<String>>;
+
+/// A registry of solutions keyed by (year, day), that runs them and prints their answers and timings.
+/// This replaces the [aoc!](../macro.aoc.html) macro.
+///
+/// By default, the input of a day is looked up with [InputResolver::from_env](../reading/struct.InputResolver.html#method.from_env),
+/// so it is read from `input/<year>/day<day>.txt` unless the environment says otherwise.
+/// A day whose input can not be loaded is reported and skipped, and the other days still run.
+/// # Examples
+/// ```
+/// extern crate libaoc;
+/// use libaoc::runner::{Runner, Solution};
+///
+/// struct Day01;
+///
+/// impl Solution for Day01 {
+///     type Part1 = usize;
+///     type Part2 = usize;
+///
+///     fn part1(&self, input: &str) -> usize {
+///         input.len()
+///     }
+///
+///     fn part2(&self, input: &str) -> usize {
+///         input.lines().count()
+///     }
+/// }
+///
+/// fn main() {
+///     let mut runner = Runner::new().with_input(|_year, _day| Ok(String::from("a\nb")));
+///     runner.register(2018, 1, Day01);
+///
+///     let mut out = Vec::new();
+///     runner.run_to(vec!["--day", "1", "--part", "2"], &mut out).unwrap();
+///
+///     let out = String::from_utf8(out).unwrap();
+///     assert!(out.starts_with("Running day 1 of year 2018:\n    part 2: 2 ("));
+///     assert!(!out.contains("part 1"));
+///
+///     // in a binary, pass the command line arguments instead:
+///     // runner.run(std::env::args().skip(1))
+/// }
+/// ```
+pub struct Runner {
+    solutions: BTreeMap<(u16, u8), Box<dyn ErasedSolution>>,
+    input: InputLoader,
+}
+
+impl Runner {
+    /// Returns a new Runner without any solutions, that loads the puzzle inputs with `InputResolver::from_env()`.
+    #[inline]
+    pub fn new() -> Runner {
+        Runner {
+            solutions: BTreeMap::new(),
+            input: resolver_loader(InputResolver::from_env()),
+        }
+    }
+
+    /// Replaces the way the puzzle input of a (year, day) is loaded.
+    #[inline]
+    pub fn with_input<F>(mut self, input: F) -> Runner
+    where
+        F: Fn(u16, u8) -> io::Result<String> + 'static,
+    {
+        self.input = Box::new(input);
+        self
+    }
+
+    /// Loads the puzzle inputs with `resolver`, instead of with the one from the environment.
+    #[inline]
+    pub fn with_resolver(mut self, resolver: InputResolver) -> Runner {
+        self.input = resolver_loader(resolver);
+        self
+    }
+
+    /// Registers the solution of a day. A solution registered earlier for the same day is replaced.
+    #[inline]
+    pub fn register<S>(&mut self, year: u16, day: u8, solution: S) -> &mut Runner
+    where
+        S: Solution + 'static,
+    {
+        self.solutions.insert((year, day), Box::new(solution));
+        self
+    }
+
+    /// Same as [`run_to`], but prints to stdout.
+    ///
+    /// [`run_to`]: #method.run_to
+    #[inline]
+    pub fn run<I, S>(&self, args: I) -> Result<(), RunError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let stdout = io::stdout();
+        let lock = stdout.lock();
+        self.run_to(args, lock)
+    }
+
+    /// Runs the solutions selected by `args`, and writes their answers and timings to `out`.
+    ///
+    /// `args` may contain `--year <year>`, `--day <day>` and `--part <1 or 2>`, or their short forms `-y`, `-d` and `-p`.
+    /// Anything that is left out is not filtered on, so no arguments at all runs everything.
+    ///
+    /// If the input of a selected day can not be loaded, that is written to `out` and the next day is run.
+    /// After all days ran, the days without input are returned as a `RunError::Input`.
+    pub fn run_to<I, S, W>(&self, args: I, mut out: W) -> Result<(), RunError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+        W: Write,
+    {
+        let selection = Selection::parse(args)?;
+        let mut ran_any = false;
+        let mut failed = Vec::new();
+
+        for (&(year, day), solution) in &self.solutions {
+            if selection.year.is_some_and(|y| y != year) || selection.day.is_some_and(|d| d != day)
+            {
+                continue;
+            }
+            ran_any = true;
+
+            writeln!(out, "Running day {} of year {}:", day, year)?;
+            let input = match (self.input)(year, day) {
+                Ok(input) => input,
+                Err(error) => {
+                    writeln!(out, "    could not load the input: {}\n", error)?;
+                    failed.push((year, day, error));
+                    continue;
+                }
+            };
+
+            for part in 1..3 {
+                if selection.part.is_some_and(|p| p != part) {
+                    continue;
+                }
+                let (answer, elapsed) = solution.run_part(part, &input);
+                writeln!(out, "    part {}: {} ({:?})", part, answer, elapsed)?;
+            }
+            writeln!(out)?;
+        }
+
+        if !ran_any {
+            Err(RunError::NoSolution {
+                year: selection.year,
+                day: selection.day,
+            })
+        } else if !failed.is_empty() {
+            Err(RunError::Input { failed })
+        } else {
+            Ok(())
+        }
+    }
+}
+
+fn resolver_loader(resolver: InputResolver) -> InputLoader {
+    Box::new(move |year, day| Ok(resolver.read::<String>(year, day)?))
+}
+
+impl Default for Runner {
+    #[inline]
+    fn default() -> Runner {
+        Runner::new()
+    }
+}
+
+/// Which solutions to run, parsed from command line arguments.
+#[derive(Debug, Default)]
+struct Selection {
+    year: Option<u16>,
+    day: Option<u8>,
+    part: Option<u8>,
+}
+
+impl Selection {
+    fn parse<I, S>(args: I) -> Result<Selection, RunError>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut selection = Selection::default();
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let arg = arg.as_ref();
+            // both `--day 3` and `--day=3` are accepted.
+            let (flag, value) = match arg.find('=') {
+                Some(idx) => (&arg[..idx], Some(arg[idx + 1..].to_string())),
+                None => (arg, None),
+            };
+            match flag {
+                "--year" | "-y" | "--day" | "-d" | "--part" | "-p" => {}
+                _ => return Err(RunError::InvalidArgument(arg.to_string())),
+            }
+
+            let value = match value {
+                Some(value) => value,
+                None => match args.next() {
+                    Some(value) => value.as_ref().to_string(),
+                    None => return Err(RunError::InvalidArgument(arg.to_string())),
+                },
+            };
+            let invalid = || RunError::InvalidArgument(format!("{} {}", flag, value));
+
+            match flag {
+                "--year" | "-y" => selection.year = Some(value.parse().map_err(|_| invalid())?),
+                "--day" | "-d" => selection.day = Some(value.parse().map_err(|_| invalid())?),
+                _ => match value.parse() {
+                    Ok(part @ 1..=2) => selection.part = Some(part),
+                    _ => return Err(invalid()),
+                },
+            }
+        }
+        Ok(selection)
+    }
+}
+
+/// The error returned when running solutions fails.
+#[derive(Debug)]
+pub enum RunError {
+    /// An argument was not recognised, or had an invalid value.
+    InvalidArgument(String),
+    /// No registered solution matched the selected year and day.
+    NoSolution { year: Option<u16>, day: Option<u8> },
+    /// The puzzle input of some days could not be loaded. Holds the (year, day) and the error of every such day.
+    /// The other days did run.
+    Input { failed: Vec<(u16, u8, io::Error)> },
+    /// Writing the answers failed.
+    Io(io::Error),
+}
+
+impl From<io::Error> for RunError {
+    #[inline]
+    fn from(error: io::Error) -> RunError {
+        RunError::Io(error)
+    }
+}
+
+impl Display for RunError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            RunError::InvalidArgument(ref arg) => write!(f, "invalid argument: {}", arg),
+            RunError::NoSolution { year, day } => {
+                write!(f, "no solution registered")?;
+                if let Some(day) = day {
+                    write!(f, " for day {}", day)?;
+                }
+                if let Some(year) = year {
+                    write!(f, " of year {}", year)?;
+                }
+                Ok(())
+            }
+            RunError::Input { ref failed } => {
+                write!(f, "could not load the input of")?;
+                for (idx, &(year, day, ref error)) in failed.iter().enumerate() {
+                    let sep = if idx == 0 { " " } else { ", " };
+                    write!(f, "{}day {} of year {} ({})", sep, day, year, error)?;
+                }
+                Ok(())
+            }
+            RunError::Io(ref error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl Error for RunError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RunError::Input { ref failed } => failed
+                .first()
+                .map(|(_, _, error)| error as &(dyn Error + 'static)),
+            RunError::Io(ref error) => Some(error),
+            RunError::InvalidArgument(_) | RunError::NoSolution { .. } => None,
+        }
+    }
+}
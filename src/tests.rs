@@ -1,3 +1,4 @@
+#[allow(unused_parens)]
 pub mod test_arraycollect {
     use convert::Convert;
     #[derive(Debug, PartialEq)]
@@ -54,3 +55,1148 @@ pub mod test_arraycollect {
         assert_eq!(vec![NonCopy::new(0), NonCopy::new(0), NonCopy::new(0)], v);
     }
 }
+
+// These tests only use safe code around the collector, so they can be run under Miri with `cargo miri test collect_array`.
+pub mod test_collect_array {
+    use convert::{CollectArray, ExactFillError, FillError};
+    use std::cell::Cell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    /// Counts how many times it was dropped.
+    #[derive(Debug)]
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl<'a> Drop for DropCounter<'a> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_collect_array_full() {
+        let drops = Cell::new(0);
+        {
+            let array = (0..3)
+                .map(|_| DropCounter(&drops))
+                .collect_array::<_, 3>()
+                .unwrap();
+            assert_eq!(0, drops.get());
+            assert_eq!(3, array.len());
+        }
+        assert_eq!(3, drops.get());
+    }
+
+    #[test]
+    fn test_collect_array_too_few_drops_partial() {
+        let drops = Cell::new(0);
+        let result = (0..2).map(|_| DropCounter(&drops)).collect_array::<_, 4>();
+
+        assert_eq!(
+            Some(FillError {
+                filled: 2,
+                expected: 4
+            }),
+            result.err()
+        );
+        assert_eq!(2, drops.get());
+    }
+
+    #[test]
+    fn test_fill_error_display() {
+        let error = FillError {
+            filled: 1,
+            expected: 3,
+        };
+        assert_eq!(
+            "The array was partially filled with 1 of 3 items, and therefore dropped.",
+            error.to_string()
+        );
+
+        let error = (0..1).collect_array_exact::<i32, 3>().unwrap_err();
+        assert_eq!(
+            "The array was partially filled with 1 of 3 items.",
+            error.to_string()
+        );
+        let error = (0..4).collect_array_exact::<i32, 3>().unwrap_err();
+        assert_eq!(
+            "The array was filled with 3 items, but the iterator had more.",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn test_collect_array_exact_too_few_returns_partial() {
+        let drops = Cell::new(0);
+        let error = (0..2)
+            .map(|_| DropCounter(&drops))
+            .collect_array_exact::<_, 4>()
+            .unwrap_err();
+
+        assert_eq!(4, error.expected());
+        assert_eq!(2, error.filled());
+        assert_eq!(0, drops.get());
+
+        let items = error.into_items();
+        assert_eq!(2, items.len());
+        drop(items);
+        assert_eq!(2, drops.get());
+    }
+
+    #[test]
+    fn test_collect_array_exact_too_many() {
+        let drops = Cell::new(0);
+        let error = (0..5)
+            .map(|_| DropCounter(&drops))
+            .collect_array_exact::<_, 3>()
+            .unwrap_err();
+
+        match error {
+            ExactFillError::TooMany {
+                expected,
+                items,
+                rest,
+            } => {
+                assert_eq!(3, expected);
+                assert_eq!(3, items.len());
+                // the item that was peeked at is not lost.
+                assert_eq!(2, rest.count());
+                assert_eq!(2, drops.get());
+            }
+            ExactFillError::TooFew { .. } => panic!("expected too many items"),
+        }
+        assert_eq!(5, drops.get());
+
+        let error = (0..2).collect_array_exact::<i32, 3>().unwrap_err();
+        match error {
+            ExactFillError::TooFew { error, items } => {
+                assert_eq!(
+                    FillError {
+                        filled: 2,
+                        expected: 3
+                    },
+                    error
+                );
+                assert_eq!(vec![0, 1], items);
+            }
+            ExactFillError::TooMany { .. } => panic!("expected too few items"),
+        }
+    }
+
+    #[test]
+    fn test_fill_errors_into_crate_error() {
+        match ::Error::from((0..1).collect_array::<i32, 2>().unwrap_err()) {
+            ::Error::Fill(error) => assert_eq!(1, error.filled),
+            other => panic!("{:?}", other),
+        }
+        match ::Error::from((0..3).collect_array_exact::<i32, 2>().unwrap_err()) {
+            ::Error::TooMany { expected } => assert_eq!(2, expected),
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_collect_array_panic_drops_partial() {
+        let drops = Cell::new(0);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            (0..4)
+                .map(|n| {
+                    if n == 3 {
+                        panic!("the iterator panics");
+                    }
+                    DropCounter(&drops)
+                })
+                .collect_array::<_, 4>()
+        }));
+
+        assert!(result.is_err());
+        assert_eq!(3, drops.get());
+    }
+
+    #[test]
+    fn test_collect_array_empty() {
+        let array: [String; 0] = Vec::new().into_iter().collect_array().unwrap();
+        assert_eq!(0, array.len());
+
+        let mut iter = vec![String::from("left")].into_iter();
+        let array: [String; 0] = (&mut iter).collect_array().unwrap();
+        assert_eq!(0, array.len());
+        assert_eq!(Some(String::from("left")), iter.next());
+    }
+
+    #[test]
+    fn test_collect_array_takes_exactly_n() {
+        let mut iter = (0..10).map(|n| n.to_string());
+        let first: [String; 4] = (&mut iter).collect_array().unwrap();
+
+        assert_eq!(["0", "1", "2", "3"], first);
+        assert_eq!(Some(String::from("4")), iter.next());
+    }
+}
+
+pub mod test_convert_exact {
+    use convert::{ConvertError, TryConvert};
+    use std::str::FromStr;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_into_slice_exact_keeps_converted_prefix() {
+        let mut buf = [0u8; 4];
+        match "1 2 x 4".split(' ').try_convert_into_slice_exact(&mut buf) {
+            Err(ConvertError::Parse { index, error }) => {
+                assert_eq!(2, index);
+                assert_eq!("invalid digit found in string", error.to_string());
+            }
+            other => panic!("{:?}", other),
+        }
+        assert_eq!([1, 2, 0, 0], buf);
+    }
+
+    #[test]
+    fn test_into_slice_exact_lengths() {
+        let mut buf = [0i32; 2];
+        assert_eq!(
+            Err(ConvertError::TooFew {
+                expected: 2,
+                found: 0
+            }),
+            "".split_whitespace().try_convert_into_slice_exact(&mut buf)
+        );
+        assert_eq!(
+            Err(ConvertError::TooMany { expected: 2 }),
+            "5 6 7".split(' ').try_convert_into_slice_exact(&mut buf)
+        );
+        // the slice is full before the extra item is looked at.
+        assert_eq!([5, 6], buf);
+
+        let mut empty: [i32; 0] = [];
+        assert_eq!(
+            Ok(()),
+            "".split_whitespace()
+                .try_convert_into_slice_exact(&mut empty)
+        );
+    }
+
+    #[test]
+    fn test_array_errors() {
+        let result: Result<[i64; 3], _> = "1,2,3,4".split(',').try_convert_array();
+        assert_eq!(Err(ConvertError::TooMany { expected: 3 }), result);
+
+        let result: Result<[i64; 3], _> = "1,-,3".split(',').try_convert_array();
+        match result {
+            Err(ConvertError::Parse { index, .. }) => assert_eq!(1, index),
+            other => panic!("{:?}", other),
+        }
+
+        let result: Result<[i64; 0], _> = "".split_whitespace().try_convert_array();
+        assert_eq!(Ok([]), result);
+    }
+
+    static DROPPED: AtomicUsize = AtomicUsize::new(0);
+
+    /// Counts how many were dropped.
+    #[derive(Debug)]
+    struct Counted;
+
+    impl FromStr for Counted {
+        type Err = ();
+
+        fn from_str(s: &str) -> Result<Counted, ()> {
+            if s == "ok" {
+                Ok(Counted)
+            } else {
+                Err(())
+            }
+        }
+    }
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            DROPPED.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn test_array_drops_converted_on_error() {
+        let result: Result<[Counted; 3], _> = "ok ok bad".split(' ').try_convert_array();
+        assert_eq!(
+            Some(ConvertError::Parse {
+                index: 2,
+                error: ()
+            }),
+            result.err()
+        );
+        assert_eq!(2, DROPPED.load(Ordering::SeqCst));
+    }
+}
+
+pub mod test_convert_options {
+    use convert::{ConvertError, ConvertOptions, TryConvert};
+    use std::num::ParseIntError;
+
+    fn convert(s: &str, options: ConvertOptions) -> Result<Vec<i32>, ConvertError<ParseIntError>> {
+        s.split(',').try_convert_with(options)
+    }
+
+    fn index_of_error(result: Result<Vec<i32>, ConvertError<ParseIntError>>) -> usize {
+        match result {
+            Err(ConvertError::Parse { index, .. }) => index,
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_default_is_strict() {
+        assert_eq!(vec![1, 2], convert("1,2", ConvertOptions::new()).unwrap());
+        assert_eq!(1, index_of_error(convert("1, 2", ConvertOptions::new())));
+        assert_eq!(2, index_of_error(convert("1,2,", ConvertOptions::new())));
+    }
+
+    #[test]
+    fn test_trim_only() {
+        let options = ConvertOptions::new().trim(true);
+        assert_eq!(vec![1, 2, 3], convert(" 1, 2 ,3\n", options).unwrap());
+        // an empty item is still an error.
+        assert_eq!(1, index_of_error(convert("1, ,3", options)));
+    }
+
+    #[test]
+    fn test_skip_empty_only() {
+        let options = ConvertOptions::new().skip_empty(true);
+        assert_eq!(vec![1, 3], convert("1,,3,", options).unwrap());
+        // without trimming, whitespace is not empty.
+        assert_eq!(1, index_of_error(convert("1, ,3", options)));
+    }
+
+    #[test]
+    fn test_tolerant() {
+        let options = ConvertOptions::tolerant();
+        assert_eq!(options, ConvertOptions::new().skip_empty(true).trim(true));
+        assert_eq!(vec![1, 3], convert(" 1, ,\t3\n,", options).unwrap());
+        assert!(convert(" , ,", options).unwrap().is_empty());
+        // skipped items still count for the index.
+        assert_eq!(3, index_of_error(convert("1,,,x", options)));
+    }
+
+    #[test]
+    fn test_convert_all_collects_every_error() {
+        let (numbers, errors): (Vec<i32>, _) = "1,x,,4, 5"
+            .split(',')
+            .try_convert_all(ConvertOptions::new());
+        assert_eq!(vec![1, 4], numbers);
+        assert_eq!(
+            vec![1, 2, 4],
+            errors.iter().map(|&(index, _)| index).collect::<Vec<_>>()
+        );
+
+        let (numbers, errors): (Vec<i32>, _) = "1,x,,4, 5"
+            .split(',')
+            .try_convert_all(ConvertOptions::tolerant());
+        assert_eq!(vec![1, 4, 5], numbers);
+        assert_eq!(
+            vec![1],
+            errors.iter().map(|&(index, _)| index).collect::<Vec<_>>()
+        );
+    }
+}
+
+pub mod test_grid {
+    use grid::Grid;
+
+    #[test]
+    fn test_zero_width_has_no_rows() {
+        let grid = Grid::new(0, 5, 'x');
+        assert_eq!(0, grid.width());
+        assert_eq!(0, grid.height());
+        assert_eq!(0, grid.rows().count());
+        assert_eq!(Grid::from_vec(0, vec![]), Some(grid));
+
+        let parsed: Grid<char> = Grid::parse("\n\n").unwrap();
+        assert_eq!(0, parsed.height());
+        assert_eq!(0, parsed.rows().count());
+    }
+
+    #[test]
+    fn test_from_vec_rejects_partial_rows() {
+        assert_eq!(None, Grid::from_vec(3, vec![1, 2, 3, 4]));
+        assert_eq!(Some(2), Grid::from_vec(3, vec![0; 6]).map(|g| g.height()));
+    }
+}
+
+pub mod test_instruction {
+    use convert::TryConvert;
+    use movement::{Direction, Instruction, Move, ParseInstructionError, Turn, TurnOrDir};
+
+    #[test]
+    fn test_steering_style() {
+        let instructions: Vec<Instruction<u32>> =
+            "R5, L90, F10, N3".split(", ").try_convert().unwrap();
+        let headings: Vec<TurnOrDir> = instructions.iter().map(|i| i.turn_or_dir).collect();
+
+        assert_eq!(
+            vec![
+                TurnOrDir::Turn(Turn::Right),
+                TurnOrDir::Turn(Turn::Left),
+                TurnOrDir::Forward,
+                TurnOrDir::Dir(Direction::Up),
+            ],
+            headings
+        );
+        assert_eq!(90, instructions[1].steps);
+    }
+
+    #[test]
+    fn test_rope_style() {
+        let moves: Vec<Move<u32>> = "R 4\nU 4\nL 3\nD 1".lines().try_convert().unwrap();
+        let directions: Vec<Direction> = moves.iter().map(|m| m.direction).collect();
+
+        assert_eq!(
+            vec![
+                Direction::Right,
+                Direction::Up,
+                Direction::Left,
+                Direction::Down
+            ],
+            directions
+        );
+        assert_eq!(
+            Instruction {
+                turn_or_dir: TurnOrDir::Dir(Direction::Left),
+                steps: 3
+            },
+            Instruction::from(moves[2])
+        );
+    }
+
+    #[test]
+    fn test_move_errors() {
+        match "F 2".parse::<Move<u32>>() {
+            Err(ParseInstructionError::Heading(e)) => assert_eq!("F", e.token),
+            other => panic!("{:?}", other),
+        }
+        match "R x".parse::<Move<u32>>() {
+            Err(ParseInstructionError::Steps { token, .. }) => assert_eq!("x", token),
+            other => panic!("{:?}", other),
+        }
+        assert!("".parse::<Move<u32>>().is_err());
+    }
+}
+
+pub mod test_pathfinding {
+    use grid::Grid;
+    use movement::Position;
+    use pathfinding::{astar_manhattan, bfs, dijkstra};
+
+    #[test]
+    fn test_multiple_starts() {
+        let maze: Grid<bool> = "....\n.##.\n....".parse().unwrap();
+        let starts = vec![Position::new(0, 0), Position::new(3, 2)];
+
+        let (steps, path) = bfs(
+            starts,
+            |&pos| {
+                maze.neighbours(pos)
+                    .filter(|&n| !maze[n])
+                    .collect::<Vec<_>>()
+            },
+            |&pos| pos == Position::new(3, 0),
+        )
+        .unwrap();
+
+        assert_eq!(2, steps);
+        assert_eq!(Position::new(3, 2), path[0]);
+    }
+
+    #[test]
+    fn test_unreachable_goal() {
+        let result = dijkstra(
+            vec![0u32],
+            |&n| if n < 10 { vec![(n + 1, 1u32)] } else { vec![] },
+            |&n| n == 11,
+        );
+        assert_eq!(None, result);
+    }
+
+    #[test]
+    fn test_astar_unsigned_start_past_goal() {
+        let grid = Grid::new(3, 3, '.');
+        let (cost, path) = astar_manhattan(
+            vec![Position::new(2usize, 2)],
+            |&pos| {
+                grid.neighbours(pos)
+                    .map(|n| (n, 1usize))
+                    .collect::<Vec<_>>()
+            },
+            Position::new(0, 0),
+        )
+        .unwrap();
+
+        assert_eq!(4, cost);
+        assert_eq!(Position::new(2, 2), path[0]);
+        assert_eq!(Some(&Position::new(0, 0)), path.last());
+    }
+
+    #[test]
+    fn test_astar_signed_cost() {
+        let neighbours = |pos: &Position<i64>| {
+            vec![
+                (*pos + Position::new(1, 0), 2i64),
+                (*pos - Position::new(0, 1), 2),
+            ]
+        };
+        let (cost, path) =
+            astar_manhattan(vec![Position::new(-3, 2)], neighbours, Position::new(0, -1)).unwrap();
+        assert_eq!(12, cost);
+        assert_eq!(7, path.len());
+    }
+
+    #[test]
+    fn test_start_is_goal() {
+        let result = bfs(vec![5], |&n| vec![n + 1], |&n| n == 5);
+        assert_eq!(Some((0, vec![5])), result);
+    }
+}
+
+pub mod test_graph {
+    use graph::Graph;
+
+    #[test]
+    fn test_long_chain_does_not_overflow() {
+        let mut graph = Graph::new();
+        for n in 0..100_000u32 {
+            graph.add_edge(n, n + 1, ());
+        }
+
+        assert_eq!(100_001, graph.strongly_connected_components().len());
+        assert!(!graph.has_cycle());
+
+        graph.add_edge(100_000, 0, ());
+        assert_eq!(1, graph.strongly_connected_components().len());
+        assert!(graph.has_cycle());
+    }
+
+    #[test]
+    fn test_self_loop_is_cycle() {
+        let graph: Graph<char> = Graph::parse_adjacency("a -> a").unwrap();
+        assert!(graph.has_cycle());
+        assert!(graph.has_undirected_cycle());
+        assert_eq!(vec![vec![&'a']], graph.connected_components());
+    }
+
+    #[test]
+    fn test_undirected_cycle() {
+        let tree: Graph<char> = Graph::parse_edges("a-b").unwrap();
+        assert!(!tree.has_undirected_cycle());
+
+        let mut graph: Graph<u32> = Graph::parse_edges("1-2\n2-3\n3-4\n1-2").unwrap();
+        assert!(!graph.has_undirected_cycle());
+        graph.add_undirected_edge(4, 2, ());
+        assert!(graph.has_undirected_cycle());
+
+        // a directed cycle is a cycle either way.
+        let directed: Graph<char> = Graph::parse_adjacency("a -> b\nb -> c\nc -> a").unwrap();
+        assert!(directed.has_undirected_cycle());
+    }
+
+    #[test]
+    fn test_parse_edges_negative_and_hyphenated() {
+        let graph: Graph<i32> = Graph::parse_edges("-1-2\n-1--3\n 4 - 5 ").unwrap();
+        assert_eq!(
+            vec![&-1, &2, &-3, &4, &5],
+            graph.nodes().collect::<Vec<_>>()
+        );
+
+        let graph: Graph<String> = Graph::parse_edges_with("he-llo->wor-ld", "->").unwrap();
+        assert_eq!(vec!["he-llo", "wor-ld"], graph.nodes().collect::<Vec<_>>());
+
+        assert!(Graph::<i32>::parse_edges("-1").is_err());
+        assert!(Graph::<i32>::parse_edges("1-").is_err());
+    }
+}
+
+pub mod test_runner {
+    use runner::{RunError, Runner, Solution};
+    use std::io;
+
+    struct Echo;
+
+    impl Solution for Echo {
+        type Part1 = String;
+        type Part2 = usize;
+
+        fn part1(&self, input: &str) -> String {
+            input.to_string()
+        }
+
+        fn part2(&self, input: &str) -> usize {
+            input.len()
+        }
+    }
+
+    fn runner() -> Runner {
+        let mut runner = Runner::new().with_input(|year, day| Ok(format!("{}-{}", year, day)));
+        runner.register(2017, 3, Echo).register(2018, 1, Echo);
+        runner
+    }
+
+    fn run(args: &[&str]) -> Result<String, RunError> {
+        let mut out = Vec::new();
+        runner().run_to(args, &mut out)?;
+        Ok(String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn test_run_everything() {
+        let out = run(&[]).unwrap();
+        assert!(out.contains("Running day 3 of year 2017:\n    part 1: 2017-3 ("));
+        assert!(out.contains("Running day 1 of year 2018:\n    part 1: 2018-1 ("));
+        assert!(out.contains("    part 2: 6 ("));
+    }
+
+    #[test]
+    fn test_select_year() {
+        let out = run(&["--year=2018"]).unwrap();
+        assert!(!out.contains("2017"));
+        assert!(out.contains("part 1: 2018-1"));
+    }
+
+    #[test]
+    fn test_missing_input_skips_only_that_day() {
+        let mut runner = Runner::new().with_input(|year, day| match year {
+            2017 => Err(io::Error::new(io::ErrorKind::NotFound, "no such file")),
+            _ => Ok(format!("{}-{}", year, day)),
+        });
+        runner.register(2017, 3, Echo).register(2018, 1, Echo);
+
+        let mut out = Vec::new();
+        match runner.run_to(&[] as &[&str], &mut out) {
+            Err(RunError::Input { ref failed }) => {
+                assert_eq!(1, failed.len());
+                assert_eq!((2017, 3), (failed[0].0, failed[0].1));
+            }
+            other => panic!("{:?}", other),
+        }
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out
+            .contains("Running day 3 of year 2017:\n    could not load the input: no such file\n"));
+        assert!(out.contains("Running day 1 of year 2018:\n    part 1: 2018-1 ("));
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        match run(&["--week", "1"]) {
+            Err(RunError::InvalidArgument(ref arg)) => assert_eq!("--week", arg),
+            _ => panic!(),
+        }
+        match run(&["-p", "3"]) {
+            Err(RunError::InvalidArgument(ref arg)) => assert_eq!("-p 3", arg),
+            _ => panic!(),
+        }
+        match run(&["--day", "25"]) {
+            Err(RunError::NoSolution {
+                year: None,
+                day: Some(25),
+            }) => {}
+            _ => panic!(),
+        }
+    }
+}
+
+pub mod test_reading {
+    use reading::{lines, records, Bytes, ReadFile, Source};
+    use std::env;
+    use std::fs;
+    use std::io::{Cursor, Read};
+    use std::process;
+    use Error;
+
+    fn collect_records(input: &str, delimiter: u8) -> Vec<String> {
+        records(Cursor::new(input), delimiter)
+            .map(Result::unwrap)
+            .collect()
+    }
+
+    #[test]
+    fn test_records_trailing_line_ending() {
+        assert_eq!(vec!["1", "2"], collect_records("1,2,\n", b','));
+        assert_eq!(vec!["1", "2"], collect_records("1,2,\r\n", b','));
+        assert_eq!(vec!["1", "2"], collect_records("1,2\r\n", b','));
+        assert_eq!(vec!["1", "2"], collect_records("1,2,", b','));
+        assert_eq!(vec!["1", "", "2"], collect_records("1,,2\n", b','));
+    }
+
+    #[test]
+    fn test_records_keep_inner_line_endings() {
+        assert_eq!(vec!["a\nb", "c"], collect_records("a\nb;c\n", b';'));
+        assert_eq!(vec!["a\r\n", "b"], collect_records("a\r\n;b", b';'));
+    }
+
+    #[test]
+    fn test_lines_strip_every_line_ending() {
+        assert_eq!(vec!["a", "", "b"], collect_records("a\r\n\r\nb\n", b'\n'));
+        assert_eq!(vec!["a", ""], collect_records("a\n\n", b'\n'));
+        assert!(collect_records("", b'\n').is_empty());
+    }
+
+    #[test]
+    fn test_read_file_errors() {
+        let path = env::temp_dir().join(format!("libaoc-test-read-errors-{}.txt", process::id()));
+
+        // a parsed Vec reports invalid UTF-8 like a String does.
+        fs::write(&path, b"1 \xff 2").unwrap();
+        match Vec::<u32>::read_file(&path) {
+            Err(Error::Utf8(_)) => {}
+            other => panic!("expected a UTF-8 error, got {:?}", other),
+        }
+        match String::read_file(&path) {
+            Err(Error::Utf8(_)) => {}
+            other => panic!("expected a UTF-8 error, got {:?}", other),
+        }
+
+        fs::write(&path, "1 -2").unwrap();
+        let error = Vec::<u32>::read_file(&path).unwrap_err();
+        assert_eq!(
+            "could not parse value 1: invalid digit found in string",
+            error.to_string()
+        );
+        fs::remove_file(&path).unwrap();
+    }
+
+    // every mapped file below is only written before it is opened, so `open_mapped` is sound.
+    #[test]
+    fn test_mapped_empty_file() {
+        let path = env::temp_dir().join(format!("libaoc-test-mapped-empty-{}.txt", process::id()));
+        fs::write(&path, "").unwrap();
+
+        let source = unsafe { Source::open_mapped(&path, 0).unwrap() };
+        assert_eq!(Bytes(Vec::new()), Bytes::read_from(source).unwrap());
+
+        let source = unsafe { Source::open_mapped(&path, 0).unwrap() };
+        assert_eq!(0, lines(source).count());
+    }
+
+    #[test]
+    fn test_mapped_lines() {
+        let path = env::temp_dir().join(format!("libaoc-test-mapped-lines-{}.txt", process::id()));
+        let content: String = (0..10_000).map(|n| format!("{}\n", n)).collect();
+        fs::write(&path, &content).unwrap();
+
+        let source = unsafe { Source::open_mapped(&path, 0).unwrap() };
+        let parsed = lines(source).parse::<u32>().map(Result::unwrap);
+        assert!(parsed.eq(0..10_000));
+
+        // reading in small chunks must not skip or repeat anything.
+        let mut source = unsafe { Source::open_mapped(&path, 0).unwrap() };
+        let mut buf = [0; 7];
+        let mut read = Vec::new();
+        loop {
+            match source.read(&mut buf).unwrap() {
+                0 => break,
+                n => read.extend_from_slice(&buf[..n]),
+            }
+        }
+        assert_eq!(content.as_bytes(), &read[..]);
+    }
+}
+
+pub mod test_input_resolver {
+    use reading::InputResolver;
+    use std::env;
+    use std::fs;
+    use std::io;
+    use std::path::{Path, PathBuf};
+    use std::process;
+    use std::sync::{Mutex, MutexGuard};
+
+    // the environment is shared by every test thread, so the tests that change it take turns.
+    static ENV_LOCK: Mutex<()> = Mutex::new(());
+
+    fn lock_env() -> MutexGuard<'static, ()> {
+        ENV_LOCK
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Returns an empty directory that no other test uses.
+    fn unique_dir(test: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("libaoc-{}-{}", test, process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn write_input(dir: &Path, year: u16, day: u8, content: &str) -> PathBuf {
+        let path = dir.join(InputResolver::relative_path(year, day));
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_defaults_without_env() {
+        let _guard = lock_env();
+        env::remove_var(InputResolver::INPUT_DIR_VAR);
+        env::remove_var(InputResolver::CACHE_DIR_VAR);
+
+        let resolver = InputResolver::from_env();
+        assert_eq!(Path::new("input"), resolver.input_dir());
+        assert_eq!(Path::new(".aoc-cache"), resolver.cache_dir());
+        assert_eq!(resolver, InputResolver::default());
+    }
+
+    #[test]
+    fn test_env_overrides() {
+        let _guard = lock_env();
+        let dir = unique_dir("resolver-env");
+        let (input, cache) = (dir.join("my-input"), dir.join("my-cache"));
+        env::set_var(InputResolver::INPUT_DIR_VAR, &input);
+        env::set_var(InputResolver::CACHE_DIR_VAR, &cache);
+
+        let resolver = InputResolver::from_env();
+        env::remove_var(InputResolver::INPUT_DIR_VAR);
+        env::remove_var(InputResolver::CACHE_DIR_VAR);
+
+        assert_eq!(input, resolver.input_dir());
+        assert_eq!(cache, resolver.cache_dir());
+
+        write_input(&input, 2019, 7, "3,9,8");
+        assert_eq!("3,9,8", resolver.read::<String>(2019, 7).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_only_one_override() {
+        let _guard = lock_env();
+        env::remove_var(InputResolver::INPUT_DIR_VAR);
+        env::set_var(InputResolver::CACHE_DIR_VAR, "somewhere-else");
+
+        let resolver = InputResolver::from_env();
+        env::remove_var(InputResolver::CACHE_DIR_VAR);
+
+        assert_eq!(Path::new("input"), resolver.input_dir());
+        assert_eq!(Path::new("somewhere-else"), resolver.cache_dir());
+    }
+
+    #[test]
+    fn test_input_dir_before_cache() {
+        let dir = unique_dir("resolver-order");
+        let resolver = InputResolver::new(dir.join("input"), dir.join("cache"));
+
+        let cached = resolver.store_in_cache(2020, 12, "cached").unwrap();
+        assert_eq!(cached, resolver.resolve(2020, 12).unwrap());
+
+        let input = write_input(resolver.input_dir(), 2020, 12, "input");
+        assert_eq!(input, resolver.resolve(2020, 12).unwrap());
+        assert_eq!("input", resolver.read::<String>(2020, 12).unwrap());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let dir = unique_dir("resolver-missing");
+        let resolver = InputResolver::new(dir.join("input"), dir.join("cache"));
+
+        let missing = resolver.resolve(2021, 1).unwrap_err();
+        assert_eq!((2021, 1), (missing.year, missing.day));
+        assert_eq!(
+            vec![
+                dir.join("input").join("2021").join("day01.txt"),
+                dir.join("cache").join("2021").join("day01.txt"),
+            ],
+            missing.searched
+        );
+
+        let error = resolver.read::<String>(2021, 1).unwrap_err();
+        match error {
+            ::Error::Io(ref error) => assert_eq!(io::ErrorKind::NotFound, error.kind()),
+            ref other => panic!("{:?}", other),
+        }
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
+
+pub mod test_parse {
+    use convert::{ConvertError, TryConvert};
+    use parse;
+    use parse::{parse_pattern, ParseGroups, ParseInts, ParseStructErrorKind, ScanErrorKind};
+    use std::error::Error;
+    use std::num::ParseIntError;
+
+    #[test]
+    fn test_parse_pattern_trailing() {
+        let error = parse_pattern("noop", "noop 1").unwrap_err();
+        assert_eq!(4, error.column);
+        match error.kind {
+            ScanErrorKind::Trailing => {}
+            _ => panic!("expected trailing input"),
+        }
+
+        // the '.' at the end of the template has to end the input.
+        let error = parse_pattern("x={}, y={}.", "x=1, y=2.!").unwrap_err();
+        assert_eq!(7, error.column);
+        match error.kind {
+            ScanErrorKind::Literal { ref expected } => assert_eq!(".", expected),
+            _ => panic!("expected a missing literal"),
+        }
+
+        assert_eq!(
+            vec!["1", "2"],
+            parse_pattern("x={}, y={}.", "x=1, y=2.").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_pattern_without_placeholders() {
+        assert!(parse_pattern("", "").unwrap().is_empty());
+        assert!(parse_pattern("noop", "noop").unwrap().is_empty());
+        assert_eq!(0, parse_pattern("noop", "addx 3").unwrap_err().column);
+    }
+
+    #[test]
+    fn test_parse_pattern_suffix() {
+        assert_eq!(vec!["1.5"], parse_pattern("{}.", "1.5.").unwrap());
+        assert_eq!(
+            vec!["a.b", "c."],
+            parse_pattern("{}: {}.", "a.b: c..").unwrap()
+        );
+        // the suffix may not overlap the text before the last placeholder.
+        assert_eq!(2, parse_pattern("a.{}.", "a.").unwrap_err().column);
+    }
+
+    #[test]
+    fn test_scan_columns() {
+        let error = scan!("x=1, y=z", "x={}, y={}" => i32, i32).unwrap_err();
+        assert_eq!(7, error.column);
+        match error.kind {
+            ScanErrorKind::Parse { ref text, .. } => assert_eq!("z", text),
+            _ => panic!("expected a parse error"),
+        }
+        assert_eq!(3, parse::parse_capture::<i32>(3, "x").unwrap_err().column);
+    }
+
+    #[test]
+    fn test_scan_single() {
+        let (value,) = scan!("addx -3", "addx {}" => i32).unwrap();
+        assert_eq!(-3, value);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_parse_pattern_adjacent_placeholders() {
+        let _ = parse_pattern("{}{}", "12");
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_scan_arity() {
+        let _ = scan!("1,2", "{},{}" => i32);
+    }
+
+    #[test]
+    fn test_ints_signs() {
+        let ints = |s: &str| s.ints::<i64>().map(Result::unwrap).collect::<Vec<_>>();
+
+        assert_eq!(vec![-1, 2, -3, 4], ints("-1 +2 --3 a4"));
+        assert_eq!(vec![1, 2, 3], ints("a-1 b+2 3-"));
+        assert_eq!(vec![10, -20], ints("<x=10,y=-20>"));
+        assert!(ints("no numbers - here +").is_empty());
+        assert_eq!(vec![7], ints("7"));
+    }
+
+    #[test]
+    fn test_ints_array_count() {
+        assert_eq!(
+            Err(ConvertError::TooMany { expected: 2 }),
+            "1 2 3".ints_array::<u32, 2>()
+        );
+        assert_eq!(
+            Err(ConvertError::TooFew {
+                expected: 4,
+                found: 3
+            }),
+            "1 2 3".ints_array::<u32, 4>()
+        );
+        assert_eq!([1, 2, 3], "1 2 3".ints_array::<u32, 3>().unwrap());
+    }
+
+    #[test]
+    fn test_ints_negative_unsigned() {
+        let mut ints = "x: -3, +4".ints::<u32>();
+        assert!(ints.next().unwrap().is_err());
+        assert_eq!(Some(Ok(4)), ints.next());
+        assert_eq!(None, ints.next());
+
+        match "y=-10..-5".ints_array::<u8, 2>() {
+            Err(ConvertError::Parse { index, .. }) => assert_eq!(0, index),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ints_overflow() {
+        assert!("x=99999999999".ints::<i32>().next().unwrap().is_err());
+
+        let ints: Vec<_> = "-128 -129 127 128".ints::<i8>().collect();
+        assert_eq!(Ok(-128), ints[0]);
+        assert!(ints[1].is_err());
+        assert_eq!(Ok(127), ints[2]);
+        assert!(ints[3].is_err());
+
+        // a number that does not fit fails the array, even if the count is right.
+        match "1 300 2".ints_array::<u8, 3>() {
+            Err(ConvertError::Parse { index, .. }) => assert_eq!(1, index),
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+        assert!("1 300 2".ints_array::<u8, 2>().is_err());
+    }
+
+    #[test]
+    fn test_groups_edges() {
+        fn groups(s: &str) -> Vec<&str> {
+            s.groups().collect()
+        }
+
+        assert!(groups("").is_empty());
+        assert!(groups("\n\r\n  \n").is_empty());
+        assert_eq!(vec!["a"], groups("\n\na"));
+        assert_eq!(vec!["a", "b\nc"], groups("a\n \t\nb\nc"));
+        assert_eq!(vec!["a\r\nb", "c"], groups("a\r\nb\r\n\r\n\r\nc"));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Move {
+        count: usize,
+        from: char,
+        to: char,
+    }
+
+    parse_struct!(Move, ' ' => count, from, to);
+
+    #[derive(Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+        z: i32,
+    }
+
+    parse_struct!(Point, ',' => x, y, z);
+
+    #[test]
+    fn test_parse_struct() {
+        let moves: Vec<Move> = "3 A B\n1 B C".lines().try_convert().unwrap();
+        assert_eq!(
+            Move {
+                count: 1,
+                from: 'B',
+                to: 'C'
+            },
+            moves[1]
+        );
+
+        let error = "3 A B C".parse::<Move>().unwrap_err();
+        assert_eq!(None, error.field);
+        match error.kind {
+            ParseStructErrorKind::TooMany { ref text } => assert_eq!("C", text),
+            _ => panic!("expected too many fields"),
+        }
+    }
+
+    #[test]
+    fn test_parse_struct_missing() {
+        for input in &["", "  ", "3 A"] {
+            let error = input.parse::<Move>().unwrap_err();
+            match error.kind {
+                ParseStructErrorKind::Missing => {}
+                _ => panic!("expected a missing field for {:?}", input),
+            }
+        }
+        assert_eq!(Some("count"), "".parse::<Move>().unwrap_err().field);
+        assert_eq!(Some("to"), "3 A".parse::<Move>().unwrap_err().field);
+
+        // an empty field between two commas is missing, and the commas are not collapsed.
+        let error = "1,,2".parse::<Point>().unwrap_err();
+        assert_eq!(Some("y"), error.field);
+        match error.kind {
+            ParseStructErrorKind::Missing => {}
+            _ => panic!("expected a missing field"),
+        }
+    }
+
+    #[test]
+    fn test_parse_struct_repeated_whitespace() {
+        assert_eq!(
+            Move {
+                count: 3,
+                from: 'A',
+                to: 'B'
+            },
+            "3  A   B".parse().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_struct_source() {
+        let error = "x A B".parse::<Move>().unwrap_err();
+        assert_eq!(Some("count"), error.field);
+        let source = error.source().unwrap();
+        assert!(source.downcast_ref::<ParseIntError>().is_some());
+    }
+}
+
+pub mod test_math {
+    use math::{crt, extended_gcd, gcd, lcm_all, mod_inv, mod_pow};
+
+    #[test]
+    fn test_crt_large_moduli() {
+        // two primes close to 2^64, so the combined modulus only fits in an u128.
+        let (p, q) = (18446744073709551557u128, 18446744073709551533u128);
+        let n = 123456789012345678901234567890u128;
+
+        assert_eq!(Some((n, p * q)), crt(&[(n % p, p), (n % q, q)]));
+        assert_eq!(None, crt(&[(5u64, p as u64), (7, q as u64)]));
+    }
+
+    #[test]
+    fn test_crt_invalid() {
+        assert_eq!(Some((0, 1)), crt::<i32>(&[]));
+        assert_eq!(None, crt(&[(1, 0)]));
+        assert_eq!(None, crt(&[(1, -5)]));
+        assert_eq!(Some((3, 5)), crt(&[(-7, 5)]));
+        assert_eq!(Some((1, 6)), crt(&[(1, 2), (1, 3), (1, 6)]));
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(i64::MAX as u64 + 1, gcd(i64::MIN as i128, 0) as u64);
+        let m = i128::MAX as u128;
+        assert_eq!(Some(m / 2 + 1), mod_inv(2, m));
+        assert_eq!(0, mod_pow(u128::MAX, 2, u128::MAX));
+        assert_eq!(0, mod_pow(5i8, 0, 1));
+        assert_eq!(Some(u64::MAX), lcm_all(vec![u64::MAX, 1, u64::MAX]));
+
+        let (g, x, y) = extended_gcd(i64::MIN, i64::MAX).unwrap();
+        assert_eq!(1, g);
+        assert_eq!(1, i64::MIN as i128 * x + i64::MAX as i128 * y);
+    }
+
+    #[test]
+    fn test_extended_gcd_overflow() {
+        assert_eq!(None, extended_gcd(i128::MIN, -1));
+        assert_eq!(None, extended_gcd(i128::MIN, 0));
+        assert_eq!(None, extended_gcd(0, i128::MIN));
+        assert_eq!(None, extended_gcd(i128::MIN, i128::MIN));
+
+        let (g, x, y) = extended_gcd(i128::MIN, 3).unwrap();
+        assert_eq!(1, g);
+        // the products do not fit in an i128, but their sum does.
+        assert_eq!(1, i128::MIN.wrapping_mul(x).wrapping_add(y.wrapping_mul(3)));
+        assert_eq!(Some((1, 0, 1)), extended_gcd(i128::MIN, 1));
+        assert_eq!(Some((1, 0, -1)), extended_gcd(i128::MIN + 1, -1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gcd_overflow() {
+        gcd(i64::MIN, 0);
+    }
+}